@@ -0,0 +1,49 @@
+//! Compares `RangeExt::overlaps_range` with the generic
+//! `RangeBounds::overlaps` on `Range<u64>`.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use rangebounds_overlaps::{RangeBounds, RangeExt};
+
+fn ranges(count: usize) -> Vec<Range<u64>> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let start = state % 10_000;
+            start..start + state % 100
+        })
+        .collect()
+}
+
+fn bench(name: &str, ranges: &[Range<u64>], overlaps: impl Fn(&Range<u64>, &Range<u64>) -> bool) {
+    let mut best = Duration::MAX;
+    for _ in 0..10 {
+        let start = Instant::now();
+        let mut count = 0usize;
+        for a in ranges {
+            for b in ranges {
+                count += overlaps(black_box(a), black_box(b)) as usize;
+            }
+        }
+        black_box(count);
+        best = best.min(start.elapsed());
+    }
+    let pairs = ranges.len() * ranges.len();
+    println!(
+        "{name:<16} {:>8.2} ns/pair",
+        best.as_nanos() as f64 / pairs as f64
+    );
+}
+
+fn main() {
+    let ranges = ranges(1_000);
+    bench("overlaps", &ranges, |a, b| a.overlaps(b));
+    bench("overlaps_range", &ranges, |a, b| a.overlaps_range(b));
+}
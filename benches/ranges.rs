@@ -0,0 +1,152 @@
+//! Baseline timings for `contains` and `overlaps` across combinations of
+//! range types and bound kinds.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::ops::Bound::{self, Excluded, Included, Unbounded};
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use rangebounds_overlaps::RangeBounds;
+
+const COUNT: usize = 1_000;
+
+fn values() -> Vec<u64> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    (0..COUNT)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % 10_000
+        })
+        .collect()
+}
+
+fn ranges(values: &[u64]) -> Vec<Range<u64>> {
+    values
+        .iter()
+        .map(|&start| start..start + start % 100)
+        .collect()
+}
+
+/// Converts each range into a bound pair, cycling through the bound kinds
+/// so every combination is exercised.
+fn tuples(ranges: &[Range<u64>]) -> Vec<(Bound<u64>, Bound<u64>)> {
+    ranges
+        .iter()
+        .enumerate()
+        .map(|(i, range)| {
+            let start = match i % 3 {
+                0 => Included(range.start),
+                1 => Excluded(range.start),
+                _ => Unbounded,
+            };
+            let end = match i / 3 % 3 {
+                0 => Included(range.end),
+                1 => Excluded(range.end),
+                _ => Unbounded,
+            };
+            (start, end)
+        })
+        .collect()
+}
+
+/// Converts each range into a bound pair with both sides bounded, cycling
+/// through the `Included` and `Excluded` kinds.
+fn bounded_tuples(ranges: &[Range<u64>]) -> Vec<(Bound<u64>, Bound<u64>)> {
+    ranges
+        .iter()
+        .enumerate()
+        .map(|(i, range)| match i % 4 {
+            0 => (Included(range.start), Excluded(range.end)),
+            1 => (Included(range.start), Included(range.end)),
+            2 => (Excluded(range.start), Excluded(range.end)),
+            _ => (Excluded(range.start), Included(range.end)),
+        })
+        .collect()
+}
+
+/// Converts each range into a bound pair with one side `Unbounded`.
+fn one_sided_tuples(ranges: &[Range<u64>]) -> Vec<(Bound<u64>, Bound<u64>)> {
+    ranges
+        .iter()
+        .enumerate()
+        .map(|(i, range)| match i % 2 {
+            0 => (Included(range.start), Unbounded),
+            _ => (Unbounded, Excluded(range.end)),
+        })
+        .collect()
+}
+
+/// Runs `f` over every pair from `a` and `b` and prints the best time per
+/// call out of ten runs.
+fn bench<A, B>(name: &str, a: &[A], b: &[B], f: impl Fn(&A, &B) -> bool) {
+    let mut best = Duration::MAX;
+    for _ in 0..10 {
+        let start = Instant::now();
+        let mut count = 0usize;
+        for x in a {
+            for y in b {
+                count += f(black_box(x), black_box(y)) as usize;
+            }
+        }
+        black_box(count);
+        best = best.min(start.elapsed());
+    }
+    let calls = a.len() * b.len();
+    println!(
+        "{name:<32} {:>8.2} ns/call",
+        best.as_nanos() as f64 / calls as f64
+    );
+}
+
+fn main() {
+    let values = values();
+    let ranges = ranges(&values);
+    let tuples = tuples(&ranges);
+    let bounded = bounded_tuples(&ranges);
+    let one_sided = one_sided_tuples(&ranges);
+    let from: Vec<_> = values.iter().map(|&start| start..).collect();
+    let to: Vec<_> = values.iter().map(|&end| ..end).collect();
+
+    bench("contains/range", &ranges, &values, |r, v| {
+        RangeBounds::contains(r, v)
+    });
+    bench("contains/tuple", &tuples, &values, |r, v| r.contains(v));
+    bench("contains/range_from", &from, &values, |r, v| {
+        RangeBounds::contains(r, v)
+    });
+
+    bench("overlaps/range_range", &ranges, &ranges, |a, b| {
+        a.overlaps(b)
+    });
+    bench("overlaps/range_tuple", &ranges, &tuples, |a, b| {
+        a.overlaps(b)
+    });
+    bench("overlaps/tuple_tuple", &tuples, &tuples, |a, b| {
+        a.overlaps(b)
+    });
+    bench("overlaps/bounded_bounded", &bounded, &bounded, |a, b| {
+        a.overlaps(b)
+    });
+    bench(
+        "overlaps/bounded_one_sided",
+        &bounded,
+        &one_sided,
+        |a, b| a.overlaps(b),
+    );
+    bench(
+        "overlaps/one_sided_one_sided",
+        &one_sided,
+        &one_sided,
+        |a, b| a.overlaps(b),
+    );
+    bench("overlaps/range_from_range_to", &from, &to, |a, b| {
+        a.overlaps(b)
+    });
+    bench("overlaps/range_full", &ranges, &[..], |a, b| {
+        a.overlaps::<_, u64>(b)
+    });
+}
@@ -0,0 +1,210 @@
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::RangeBounds;
+
+/// A range described by a start and an end [`Bound`].
+///
+/// `BoundRange` holds the same data as a `(Bound<T>, Bound<T>)` pair and
+/// converts to and from one, but as a named type it can be stored in structs
+/// and configuration without spelling out the pair. With the `serde` feature
+/// it implements `Serialize` and `Deserialize`, writing the two bounds as a
+/// sequence in the same form serde uses for `Bound`. With the `arbitrary`
+/// feature it implements `Arbitrary`, so fuzzers can generate ranges. With
+/// the `rkyv` feature it implements rkyv's `Archive`, `Serialize` and
+/// `Deserialize`; the archived form, `ArchivedBoundRange`, implements
+/// [`RangeBounds`] over the archived values, so stored ranges can be
+/// queried without deserializing them.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{BoundRange, RangeBounds};
+/// use std::ops::Bound::*;
+///
+/// let range = BoundRange(Excluded(0), Included(3));
+/// assert!(range.overlaps(&(3..5)));
+/// assert_eq!(<(_, _)>::from(range), (Excluded(0), Included(3)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct BoundRange<T>(pub Bound<T>, pub Bound<T>);
+
+impl<T> RangeBounds<T> for BoundRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        match self.0 {
+            Included(ref start) => Included(start),
+            Excluded(ref start) => Excluded(start),
+            Unbounded => Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        match self.1 {
+            Included(ref end) => Included(end),
+            Excluded(ref end) => Excluded(end),
+            Unbounded => Unbounded,
+        }
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for BoundRange<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        BoundRange(start, end)
+    }
+}
+
+impl<T> From<BoundRange<T>> for (Bound<T>, Bound<T>) {
+    fn from(range: BoundRange<T>) -> Self {
+        (range.0, range.1)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: rkyv::Archive> RangeBounds<T::Archived> for ArchivedBoundRange<T> {
+    fn start_bound(&self) -> Bound<&T::Archived> {
+        self.0.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T::Archived> {
+        self.1.as_ref()
+    }
+}
+
+/// Picks each bound independently, as `Included`, `Excluded` or
+/// `Unbounded` around an arbitrary value, so the generated ranges include
+/// unbounded ones, empty ones and ones whose start is past their end.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for BoundRange<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(BoundRange(u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let bound = <Bound<T> as arbitrary::Arbitrary>::size_hint(depth);
+        arbitrary::size_hint::and(bound, bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds<T: Copy>(value: T) -> [Bound<T>; 3] {
+        [Included(value), Excluded(value), Unbounded]
+    }
+
+    #[test]
+    fn round_trips_through_pair() {
+        for start in bounds(0) {
+            for end in bounds(3) {
+                let range = BoundRange::from((start, end));
+                assert_eq!(range.start_bound(), start.as_ref());
+                assert_eq!(range.end_bound(), end.as_ref());
+                assert_eq!(<(_, _)>::from(range), (start, end));
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_serde() {
+        for start in bounds(0) {
+            for end in bounds(3) {
+                let range = BoundRange(start, end);
+                let json = serde_json::to_string(&range).unwrap();
+                assert_eq!(
+                    serde_json::from_str::<BoundRange<i32>>(&json).unwrap(),
+                    range
+                );
+            }
+        }
+        assert_eq!(
+            serde_json::to_string(&BoundRange(Excluded(0), Unbounded)).unwrap(),
+            r#"[{"Excluded":0},"Unbounded"]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<BoundRange<f64>>(r#"[{"Included":0.5},{"Excluded":2.0}]"#)
+                .unwrap(),
+            BoundRange(Included(0.5), Excluded(2.0))
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_covers_every_kind_of_range() {
+        use arbitrary::{Arbitrary, Unstructured};
+        use std::vec::Vec;
+
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let bytes: Vec<u8> = (0..4096)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        let mut u = Unstructured::new(&bytes);
+        let mut seen = [false; 5];
+        while !u.is_empty() {
+            let range = BoundRange::<u8>::arbitrary(&mut u).unwrap();
+            let backwards = matches!(
+                (range.0, range.1),
+                (Included(start) | Excluded(start), Included(end) | Excluded(end)) if start > end
+            );
+            seen[0] |= matches!(range.0, Unbounded);
+            seen[1] |= matches!(range.1, Unbounded);
+            seen[2] |= matches!((range.0, range.1), (Excluded(_), Included(_)));
+            seen[3] |= backwards;
+            seen[4] |= range.is_empty() && !backwards;
+        }
+        assert_eq!(seen, [true; 5]);
+        assert_eq!(BoundRange::<u8>::size_hint(0), (2, Some(4)));
+    }
+
+    #[cfg(all(feature = "rkyv", feature = "alloc"))]
+    #[test]
+    fn round_trips_through_rkyv() {
+        use rkyv::rancor::Error;
+        use std::vec::Vec;
+
+        let ranges: Vec<BoundRange<u32>> = bounds(0)
+            .into_iter()
+            .flat_map(|start| bounds(3).map(move |end| BoundRange(start, end)))
+            .collect();
+        let bytes = rkyv::to_bytes::<Error>(&ranges).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Vec<BoundRange<u32>>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), ranges.len());
+        for (archived, range) in archived.iter().zip(&ranges) {
+            assert_eq!(
+                archived.start_bound().map(|start| start.to_native()),
+                range.0
+            );
+            assert_eq!(archived.end_bound().map(|end| end.to_native()), range.1);
+            assert_eq!(archived.is_empty(), range.is_empty());
+            assert_eq!(
+                archived.contains(&rkyv::Archived::<u32>::from_native(3)),
+                RangeBounds::contains(range, &3)
+            );
+        }
+        assert_eq!(
+            rkyv::deserialize::<Vec<BoundRange<u32>>, Error>(archived).unwrap(),
+            ranges
+        );
+    }
+
+    #[test]
+    fn overlaps_like_pair() {
+        for start in bounds(0) {
+            for end in bounds(3) {
+                let range = BoundRange(start, end);
+                assert_eq!(range.overlaps(&(3..5)), (start, end).overlaps(&(3..5)));
+                assert_eq!(range.contains(&3), RangeBounds::contains(&(start, end), &3));
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Shorthand constructors for bound pairs.
+//!
+//! Each function names the kind of its two bounds in interval notation and
+//! returns a `(Bound<T>, Bound<T>)`, which implements [`RangeBounds`]:
+//!
+//! ```
+//! use rangebounds_overlaps::bounds::{closed, open};
+//! use rangebounds_overlaps::RangeBounds;
+//!
+//! assert!(open(0, 3).overlaps(&closed(2, 5)));
+//! assert!(!open(0, 3).overlaps(&closed(3, 5)));
+//! ```
+//!
+//! [`RangeBounds`]: crate::RangeBounds
+
+use core::ops::Bound::{self, Excluded, Included};
+
+/// Returns the range `(start, end)`, which excludes both endpoints.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::bounds::open;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(open(0, 3), (Excluded(0), Excluded(3)));
+/// ```
+pub const fn open<T>(start: T, end: T) -> (Bound<T>, Bound<T>) {
+    (Excluded(start), Excluded(end))
+}
+
+/// Returns the range `[start, end]`, which includes both endpoints, like
+/// `start..=end`.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::bounds::closed;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(closed(0, 3), (Included(0), Included(3)));
+/// ```
+pub const fn closed<T>(start: T, end: T) -> (Bound<T>, Bound<T>) {
+    (Included(start), Included(end))
+}
+
+/// Returns the range `[start, end)`, which includes its start and excludes
+/// its end, like `start..end`.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::bounds::closed_open;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(closed_open(0, 3), (Included(0), Excluded(3)));
+/// ```
+pub const fn closed_open<T>(start: T, end: T) -> (Bound<T>, Bound<T>) {
+    (Included(start), Excluded(end))
+}
+
+/// Returns the range `(start, end]`, which excludes its start and includes
+/// its end.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::bounds::open_closed;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(open_closed(0, 3), (Excluded(0), Included(3)));
+/// ```
+pub const fn open_closed<T>(start: T, end: T) -> (Bound<T>, Bound<T>) {
+    (Excluded(start), Included(end))
+}
+
+/// Returns the half-open range `[start, end)`, the same as
+/// [`closed_open`].
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::bounds::half_open;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(half_open(0, 3), (Included(0), Excluded(3)));
+/// ```
+pub const fn half_open<T>(start: T, end: T) -> (Bound<T>, Bound<T>) {
+    closed_open(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RangeBounds;
+
+    const OPEN: (Bound<u8>, Bound<u8>) = open(0, 3);
+
+    #[test]
+    fn constructors() {
+        assert_eq!(OPEN, (Excluded(0), Excluded(3)));
+        for start in 0..=3 {
+            for end in 0..=3 {
+                for value in -1..=4 {
+                    assert_eq!(
+                        closed(start, end).contains(&value),
+                        (start..=end).contains(&value)
+                    );
+                    assert_eq!(
+                        closed_open(start, end).contains(&value),
+                        (start..end).contains(&value)
+                    );
+                    assert_eq!(
+                        half_open(start, end).contains(&value),
+                        (start..end).contains(&value)
+                    );
+                    assert_eq!(
+                        open(start, end).contains(&value),
+                        start < value && value < end
+                    );
+                    assert_eq!(
+                        open_closed(start, end).contains(&value),
+                        start < value && value <= end
+                    );
+                }
+            }
+        }
+    }
+}
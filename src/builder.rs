@@ -0,0 +1,279 @@
+use core::cmp::Ordering;
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::RangeError;
+
+/// Builds a `(Bound<T>, Bound<T>)` range one bound at a time, checking
+/// that the result holds at least one value.
+///
+/// Both bounds start out `Unbounded`. Setting a bound again replaces it, so
+/// a parser can set each side as it reads it and call [`build`] once at the
+/// end.
+///
+/// [`build`]: RangeBuilder::build
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{RangeBuilder, RangeError};
+/// use std::ops::Bound::*;
+///
+/// let range = RangeBuilder::new().start_excluded(3).end_included(5).build();
+/// assert_eq!(range, Ok((Excluded(3), Included(5))));
+///
+/// let range = RangeBuilder::new().start_included(5).end_excluded(3).build();
+/// assert_eq!(range, Err(RangeError::Backwards));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeBuilder<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+impl<T> RangeBuilder<T> {
+    /// Returns a builder for the unbounded range `..`.
+    pub fn new() -> Self {
+        RangeBuilder {
+            start: Unbounded,
+            end: Unbounded,
+        }
+    }
+
+    /// Returns a builder that starts from the bounds of `pair`, so an
+    /// existing range can be adjusted or checked with [`build`].
+    ///
+    /// [`build`]: RangeBuilder::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::bounds::closed;
+    /// use rangebounds_overlaps::{RangeBuilder, RangeError};
+    /// use std::ops::Bound::*;
+    ///
+    /// let range = RangeBuilder::from_pair(closed(3, 5)).end_excluded(9).build();
+    /// assert_eq!(range, Ok((Included(3), Excluded(9))));
+    ///
+    /// let range = RangeBuilder::from_pair((Excluded(5), Excluded(5))).build();
+    /// assert_eq!(range, Err(RangeError::EmptyExcluded));
+    /// ```
+    pub fn from_pair((start, end): (Bound<T>, Bound<T>)) -> Self {
+        RangeBuilder { start, end }
+    }
+
+    /// Starts the range at `value`, inclusive.
+    pub fn start_included(self, value: T) -> Self {
+        RangeBuilder {
+            start: Included(value),
+            ..self
+        }
+    }
+
+    /// Starts the range just after `value`.
+    pub fn start_excluded(self, value: T) -> Self {
+        RangeBuilder {
+            start: Excluded(value),
+            ..self
+        }
+    }
+
+    /// Leaves the range's start unbounded.
+    pub fn start_unbounded(self) -> Self {
+        RangeBuilder {
+            start: Unbounded,
+            ..self
+        }
+    }
+
+    /// Ends the range at `value`, inclusive.
+    pub fn end_included(self, value: T) -> Self {
+        RangeBuilder {
+            end: Included(value),
+            ..self
+        }
+    }
+
+    /// Ends the range just before `value`.
+    pub fn end_excluded(self, value: T) -> Self {
+        RangeBuilder {
+            end: Excluded(value),
+            ..self
+        }
+    }
+
+    /// Leaves the range's end unbounded.
+    pub fn end_unbounded(self) -> Self {
+        RangeBuilder {
+            end: Unbounded,
+            ..self
+        }
+    }
+
+    /// Returns the range, or an error if it holds no values.
+    ///
+    /// # Errors
+    ///
+    /// - [`RangeError::Backwards`] if the start is past the end, as in
+    ///   `5..3`.
+    /// - [`RangeError::EmptyExcluded`] if the start and end are equal and at
+    ///   least one of them is excluded, as in `5..5`.
+    /// - [`RangeError::Incomparable`] if a bound's value cannot be compared,
+    ///   as with a NaN.
+    pub fn build(self) -> Result<(Bound<T>, Bound<T>), RangeError>
+    where
+        T: PartialOrd,
+    {
+        if !crate::is_comparable(self.start.as_ref()) || !crate::is_comparable(self.end.as_ref()) {
+            return Err(RangeError::Incomparable);
+        }
+        let (start, end) = match (&self.start, &self.end) {
+            (Included(start) | Excluded(start), Included(end) | Excluded(end)) => (start, end),
+            _ => return Ok((self.start, self.end)),
+        };
+        match start.partial_cmp(end) {
+            Some(Ordering::Less) => Ok((self.start, self.end)),
+            Some(Ordering::Equal) => match (&self.start, &self.end) {
+                (Included(_), Included(_)) => Ok((self.start, self.end)),
+                _ => Err(RangeError::EmptyExcluded),
+            },
+            Some(Ordering::Greater) => Err(RangeError::Backwards),
+            None => Err(RangeError::Incomparable),
+        }
+    }
+}
+
+impl<T> Default for RangeBuilder<T> {
+    fn default() -> Self {
+        RangeBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_valid_ranges() {
+        assert_eq!(
+            RangeBuilder::<i32>::new().build(),
+            Ok((Unbounded, Unbounded))
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_included(3)
+                .end_excluded(5)
+                .build(),
+            Ok((Included(3), Excluded(5)))
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_included(5)
+                .end_included(5)
+                .build(),
+            Ok((Included(5), Included(5)))
+        );
+        assert_eq!(
+            RangeBuilder::new().end_included(-5).build(),
+            Ok((Unbounded, Included(-5)))
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_excluded(9)
+                .end_unbounded()
+                .build(),
+            Ok((Excluded(9), Unbounded))
+        );
+    }
+
+    #[test]
+    fn starts_from_a_pair() {
+        assert_eq!(
+            RangeBuilder::from_pair((Excluded(0), Included(2))).build(),
+            Ok((Excluded(0), Included(2)))
+        );
+        assert_eq!(
+            RangeBuilder::from_pair((Included(5), Unbounded))
+                .start_unbounded()
+                .build(),
+            Ok((Unbounded, Unbounded))
+        );
+        assert_eq!(
+            RangeBuilder::from_pair((Included(5), Excluded(3))).build(),
+            Err(RangeError::Backwards)
+        );
+        assert_eq!(
+            RangeBuilder::from_pair((Included(f64::NAN), Unbounded)).build(),
+            Err(RangeError::Incomparable)
+        );
+    }
+
+    #[test]
+    fn later_setters_replace_earlier_ones() {
+        assert_eq!(
+            RangeBuilder::new()
+                .start_included(5)
+                .end_included(3)
+                .start_unbounded()
+                .build(),
+            Ok((Unbounded, Included(3)))
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .end_excluded(1)
+                .start_excluded(0)
+                .end_included(2)
+                .build(),
+            Ok((Excluded(0), Included(2)))
+        );
+    }
+
+    #[test]
+    fn rejects_empty_ranges() {
+        assert_eq!(
+            RangeBuilder::new()
+                .start_included(5)
+                .end_excluded(3)
+                .build(),
+            Err(RangeError::Backwards)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_excluded(5)
+                .end_included(3)
+                .build(),
+            Err(RangeError::Backwards)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_included(5)
+                .end_excluded(5)
+                .build(),
+            Err(RangeError::EmptyExcluded)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_excluded(5)
+                .end_included(5)
+                .build(),
+            Err(RangeError::EmptyExcluded)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_excluded(5)
+                .end_excluded(5)
+                .build(),
+            Err(RangeError::EmptyExcluded)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_included(f64::NAN)
+                .end_included(1.0)
+                .build(),
+            Err(RangeError::Incomparable)
+        );
+        assert_eq!(
+            RangeBuilder::new().start_excluded(f64::NAN).build(),
+            Err(RangeError::Incomparable)
+        );
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::sealed::SealedRange;
+#[cfg(feature = "alloc")]
+use crate::CowRange;
+use crate::{BoundRange, CanonRange, IndexRange, Lower, RangeBounds, Reversed, Upper};
+
+/// A [`RangeBounds`] implementation provided by this crate.
+///
+/// `RangeBounds` can be implemented outside this crate, and every provided
+/// method trusts `start_bound` and `end_bound` to describe the range
+/// faithfully and consistently between calls. `BuiltinRange` is sealed and
+/// implemented only for the range types whose impls live here: the std
+/// range types, bound pairs, `[T; 2]`, [`BoundRange`], [`CanonRange`],
+/// [`Lower`], [`Upper`], [`IndexRange`], `CowRange` with the `alloc`
+/// feature, and [`Reversed`] wrappers of and references to any of those.
+/// Code that must not depend on third-party impls can take
+/// `impl BuiltinRange<T>` instead of `impl RangeBounds<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{BuiltinRange, RangeBounds};
+///
+/// fn overlap_count<T, Q, R>(query: &Q, ranges: &[R]) -> usize
+/// where
+///     T: PartialOrd,
+///     Q: BuiltinRange<T>,
+///     R: BuiltinRange<T>,
+/// {
+///     ranges.iter().filter(|range| query.overlaps(*range)).count()
+/// }
+///
+/// assert_eq!(overlap_count(&(0..5), &[3..7, 5..9]), 1);
+/// ```
+pub trait BuiltinRange<T: ?Sized>: RangeBounds<T> + SealedRange<T> {}
+
+impl<T: ?Sized, R: ?Sized + RangeBounds<T> + SealedRange<T>> BuiltinRange<T> for R {}
+
+impl<T: ?Sized> SealedRange<T> for RangeFull {}
+impl<T> SealedRange<T> for RangeFrom<T> {}
+impl<T> SealedRange<T> for RangeTo<T> {}
+impl<T> SealedRange<T> for Range<T> {}
+impl<T> SealedRange<T> for RangeInclusive<T> {}
+impl<T> SealedRange<T> for RangeToInclusive<T> {}
+impl<T> SealedRange<T> for RangeFrom<&T> {}
+impl<T> SealedRange<T> for RangeTo<&T> {}
+impl<T> SealedRange<T> for Range<&T> {}
+impl<T> SealedRange<T> for RangeInclusive<&T> {}
+impl<T> SealedRange<T> for RangeToInclusive<&T> {}
+impl<T> SealedRange<T> for (Bound<T>, Bound<T>) {}
+impl<'a, 'b, T: ?Sized + 'a + 'b> SealedRange<T> for (Bound<&'a T>, Bound<&'b T>) {}
+impl<T> SealedRange<T> for [T; 2] {}
+impl<T> SealedRange<T> for BoundRange<T> {}
+impl<T> SealedRange<T> for CanonRange<T> {}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + ToOwned> SealedRange<T> for CowRange<'_, T> {}
+impl<T> SealedRange<T> for Lower<T> {}
+impl<T> SealedRange<T> for Upper<T> {}
+impl SealedRange<usize> for IndexRange {}
+impl<T: ?Sized, R: SealedRange<T>> SealedRange<T> for Reversed<R> {}
+impl<T: ?Sized, R: ?Sized + SealedRange<T>> SealedRange<T> for &R {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+
+    fn builtin<T: ?Sized, R: ?Sized + BuiltinRange<T>>(_: &R) {}
+
+    #[test]
+    fn implemented_for_crate_ranges() {
+        builtin::<i32, _>(&..);
+        builtin(&(0..));
+        builtin(&(..0));
+        builtin(&(0..1));
+        builtin(&(0..=1));
+        builtin(&(..=1));
+        builtin::<i32, _>(&(&0..));
+        builtin::<i32, _>(&(&0..&1));
+        builtin(&(Included(0), Excluded(1)));
+        builtin::<i32, _>(&(Included(&0), Unbounded));
+        builtin(&[0, 1]);
+        builtin(&BoundRange(Included(0), Unbounded));
+        builtin(&CanonRange::new(&(0..=1)));
+        builtin(&Lower(Included(0)));
+        builtin(&Upper(Included(0)));
+        builtin(&IndexRange(3));
+        builtin(&Reversed(0..1));
+        builtin(&Reversed(&Reversed(..=1)));
+        builtin(&&&(0..1));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn implemented_for_cow_ranges() {
+        use alloc::borrow::Cow;
+
+        builtin(&CowRange(Included(Cow::<i32>::Owned(0)), Unbounded));
+        builtin::<str, _>(&CowRange(
+            Included(Cow::Borrowed("a")),
+            Excluded(Cow::Borrowed("b")),
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::{Countable, RangeBounds};
+
+/// A range of [`Countable`] values in a canonical form, so that two
+/// `CanonRange`s are equal, and hash equally, exactly when they hold the same
+/// values.
+///
+/// Ranges built from different encodings of the same values, such as `0..5`,
+/// `0..=4` and `(Excluded(-1), Excluded(5))`, are rewritten to the same
+/// `Included(first)..=Included(last)` bounds, with an `Unbounded` side
+/// replaced by the type's least or greatest value. Every empty range becomes
+/// the single form `MIN..MIN`. This makes `CanonRange` usable as a `HashMap`
+/// or `BTreeMap` key for deduplicating ranges, where the raw
+/// `(Bound<T>, Bound<T>)` pairs would compare structurally.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::CanonRange;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(CanonRange::new(&(0..5)), CanonRange::new(&(0..=4)));
+/// assert_eq!(
+///     CanonRange::new(&(Excluded(-1), Excluded(5))),
+///     CanonRange::new(&(0..5)),
+/// );
+/// assert_eq!(CanonRange::new(&(..=4u8)), CanonRange::new(&(0..5u8)));
+/// assert_eq!(CanonRange::new(&(5..5)), CanonRange::new(&(9..=2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CanonRange<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+impl<T: Countable> CanonRange<T> {
+    /// Returns the canonical form of `range`.
+    pub fn new<R: ?Sized + RangeBounds<T>>(range: &R) -> Self {
+        let first = match range.start_bound() {
+            Included(start) => Some(start.clone()),
+            Excluded(start) => start.successor(),
+            Unbounded => Some(T::MIN),
+        };
+        let last = match range.end_bound() {
+            Included(end) => Some(end.clone()),
+            Excluded(end) => end.predecessor(),
+            Unbounded => Some(T::MAX),
+        };
+        match (first, last) {
+            (Some(first), Some(last)) if first <= last => CanonRange {
+                start: Included(first),
+                end: Included(last),
+            },
+            _ => CanonRange {
+                start: Included(T::MIN),
+                end: Excluded(T::MIN),
+            },
+        }
+    }
+}
+
+impl<T> CanonRange<T> {
+    /// Returns the canonical bounds as an owned pair.
+    pub fn into_inner(self) -> (Bound<T>, Bound<T>) {
+        (self.start, self.end)
+    }
+}
+
+impl<T> RangeBounds<T> for CanonRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.end.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn equivalent_encodings_collide() {
+        let encodings: [(Bound<i32>, Bound<i32>); 4] = [
+            (Included(0), Excluded(5)),
+            (Included(0), Included(4)),
+            (Excluded(-1), Excluded(5)),
+            (Excluded(-1), Included(4)),
+        ];
+        let set: HashSet<_> = encodings.iter().map(CanonRange::new).collect();
+        assert_eq!(set.len(), 1);
+        assert_eq!(
+            set.into_iter().next().unwrap().into_inner(),
+            (Included(0), Included(4))
+        );
+        assert_ne!(CanonRange::new(&(0..5)), CanonRange::new(&(0..6)));
+        assert_ne!(CanonRange::new(&(0..5)), CanonRange::new(&(1..5)));
+    }
+
+    #[test]
+    fn unbounded_sides_use_type_limits() {
+        assert_eq!(
+            CanonRange::new(&(..)),
+            CanonRange::new(&(u8::MIN..=u8::MAX))
+        );
+        assert_eq!(CanonRange::new(&(3i8..)), CanonRange::new(&(3..=i8::MAX)));
+        assert_eq!(
+            CanonRange::new(&(..3i8)),
+            CanonRange::new(&(Included(i8::MIN), Included(2)))
+        );
+    }
+
+    #[test]
+    fn empty_ranges_collide() {
+        let set: HashSet<_> = [
+            CanonRange::new(&(5..5)),
+            CanonRange::new(&(Included(9), Included(2))),
+            CanonRange::new(&(Excluded(4), Excluded(5))),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&CanonRange::new(&(i32::MIN..i32::MIN))));
+
+        let empty = CanonRange::new(&(0u8..0));
+        assert_eq!(CanonRange::new(&(Excluded(u8::MAX), Unbounded)), empty);
+        assert_eq!(CanonRange::new(&(..0u8)), empty);
+        assert_eq!(empty.into_inner(), (Included(0), Excluded(0)));
+    }
+
+    #[test]
+    fn holds_the_same_values() {
+        let range = CanonRange::new(&(Excluded(2), Excluded(6)));
+        assert!(!range.contains(&2));
+        assert!(range.contains(&3));
+        assert!(range.contains(&5));
+        assert!(!range.contains(&6));
+        assert!(range.overlaps(&(5..)));
+        assert!(!range.overlaps(&(6..)));
+        assert!(CanonRange::new(&(3..3)).is_empty());
+    }
+}
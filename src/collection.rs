@@ -0,0 +1,617 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Bound;
+
+#[cfg(feature = "alloc")]
+use crate::Countable;
+use crate::RangeBounds;
+
+/// Returns `true` if `query` overlaps any of `ranges`.
+///
+/// Stops at the first range that overlaps `query`, so the remaining ranges
+/// are not visited.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::overlaps_any;
+///
+/// let ranges = vec![0..3, 10..20, 40..50];
+/// assert!( overlaps_any(&(15..30), ranges.iter().cloned()));
+/// assert!(!overlaps_any(&(20..40), ranges.iter().cloned()));
+/// ```
+#[must_use]
+pub fn overlaps_any<T, Q, O, I>(query: &Q, ranges: I) -> bool
+where
+    T: ?Sized + PartialOrd,
+    Q: ?Sized + RangeBounds<T>,
+    O: RangeBounds<T>,
+    I: IntoIterator<Item = O>,
+{
+    ranges.into_iter().any(|range| query.overlaps(&range))
+}
+
+/// Removes every range in `ranges` that does not overlap `query`, keeping
+/// the rest in their original order.
+///
+/// Empty ranges overlap nothing, so they are always removed.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::retain_overlapping;
+/// use std::ops::Bound::*;
+///
+/// let mut ranges = vec![
+///     (Included(0), Excluded(10)),
+///     (Included(40), Excluded(50)),
+///     (Included(15), Excluded(25)),
+/// ];
+/// retain_overlapping(&mut ranges, &(5..20));
+/// assert_eq!(ranges, [(Included(0), Excluded(10)), (Included(15), Excluded(25))]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn retain_overlapping<T, E, O>(ranges: &mut Vec<(Bound<T>, Bound<T>)>, query: &O)
+where
+    T: PartialOrd + PartialOrd<E>,
+    E: ?Sized + PartialOrd + PartialOrd<T>,
+    O: RangeBounds<E>,
+{
+    ranges.retain(|range| range.overlaps(query));
+}
+
+/// Returns the smallest range enclosing all of `ranges`, or `None` if there
+/// are none.
+///
+/// The ranges are folded together with
+/// [`bounding_range`](RangeBounds::bounding_range), so any `Unbounded` start
+/// or end makes the hull's start or end `Unbounded`, and empty ranges are
+/// ignored unless every range is empty.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::hull_of;
+/// use std::ops::Bound::{self, *};
+///
+/// let hull = hull_of([(Included(5), Excluded(8)), (Excluded(0), Included(3))]);
+/// assert_eq!(hull, Some((Excluded(0), Excluded(8))));
+/// assert_eq!(hull_of(std::iter::empty::<(Bound<i32>, Bound<i32>)>()), None);
+/// ```
+#[must_use]
+pub fn hull_of<T: Ord + Clone>(
+    ranges: impl IntoIterator<Item = (Bound<T>, Bound<T>)>,
+) -> Option<(Bound<T>, Bound<T>)> {
+    ranges
+        .into_iter()
+        .reduce(|hull, range| hull.bounding_range(&range))
+}
+
+/// Returns the index of the range in `ranges` that contains `value`, or
+/// `None` if no range does.
+///
+/// `ranges` must be sorted and pairwise disjoint, such as the output of
+/// `merge_overlapping`; the lookup is a binary search over the end bounds,
+/// so it takes `O(log n)` comparisons. A value on a shared boundary belongs
+/// to whichever range includes it.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::find_containing;
+/// use std::ops::Bound::*;
+///
+/// let ranges = [
+///     (Unbounded, Excluded(0)),
+///     (Included(0), Included(5)),
+///     (Excluded(5), Excluded(9)),
+/// ];
+/// assert_eq!(find_containing(&ranges, &-7), Some(0));
+/// assert_eq!(find_containing(&ranges, &5), Some(1));
+/// assert_eq!(find_containing(&ranges, &9), None);
+/// ```
+#[must_use]
+pub fn find_containing<T: Ord>(ranges: &[(Bound<T>, Bound<T>)], value: &T) -> Option<usize> {
+    let index = ranges.partition_point(|range| match range.end_bound() {
+        Bound::Included(end) => end < value,
+        Bound::Excluded(end) => end <= value,
+        Bound::Unbounded => false,
+    });
+    ranges
+        .get(index)
+        .filter(|range| RangeBounds::contains(*range, value))
+        .map(|_| index)
+}
+
+/// Coalesces `ranges` into the fewest disjoint ranges covering the same
+/// elements.
+///
+/// Ranges that overlap or [touch](RangeBounds::touches), such as `0..3` and
+/// `3..5`, are combined with [`union`](RangeBounds::union); empty ranges
+/// cover nothing and are dropped. The result is sorted by start bound, and
+/// any two of its ranges have a gap between them.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::merge_overlapping;
+/// use std::ops::Bound::*;
+///
+/// let merged = merge_overlapping(vec![
+///     (Included(5), Excluded(8)),
+///     (Included(0), Excluded(3)),
+///     (Included(3), Excluded(4)),
+/// ]);
+/// assert_eq!(
+///     merged,
+///     vec![(Included(0), Excluded(4)), (Included(5), Excluded(8))],
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn merge_overlapping<T: Ord + Clone>(
+    ranges: impl IntoIterator<Item = (Bound<T>, Bound<T>)>,
+) -> Vec<(Bound<T>, Bound<T>)> {
+    merge_by(ranges, |last, range| last.union(range).is_some())
+}
+
+/// Returns `true` if every element of `target` lies in at least one of the
+/// ranges in `cover`.
+///
+/// The ranges are combined with [`merge_overlapping`] first, so `target`
+/// may span several of them as long as they leave no gap inside it; `0..3`
+/// and `3..5` together cover `0..5`, but `..3` and `(Excluded(3), Unbounded)`
+/// miss the point `3`. As everywhere in this crate the values are treated as
+/// dense, so `0..=2` and `3..=5` do not cover `0..=5` even for integers. An
+/// empty `target` is covered by anything.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::is_covered_by;
+/// use std::ops::Bound::*;
+///
+/// let shifts = [(Included(9), Excluded(13)), (Included(12), Excluded(17))];
+/// assert!(is_covered_by(&(Included(9), Excluded(17)), &shifts));
+/// assert!(!is_covered_by(&(Included(8), Excluded(17)), &shifts));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn is_covered_by<T: Ord + Clone>(
+    target: &(Bound<T>, Bound<T>),
+    cover: &[(Bound<T>, Bound<T>)],
+) -> bool {
+    target.is_empty()
+        || merge_overlapping(cover.iter().cloned())
+            .iter()
+            .any(|range| range.contains_range(target))
+}
+
+/// Which ranges [`merge`] combines.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergeMode {
+    /// Combine ranges that share an element, such as `0..4` and `3..6`.
+    OverlapOnly,
+    /// Also combine ranges that [touch](RangeBounds::touches) with no gap
+    /// between them, such as `0..3` and `3..6`, like [`merge_overlapping`].
+    Touching,
+}
+
+/// Coalesces `ranges` into the fewest ranges that cover the same elements,
+/// combining the ranges that `mode` treats as connected.
+///
+/// As with [`merge_overlapping`], empty ranges are dropped and the result is
+/// sorted by start bound. To also combine integer ranges with no integer
+/// between them, use [`merge_integer_adjacent`].
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{merge, MergeMode};
+/// use std::ops::Bound::*;
+///
+/// let ranges = [(Included(0), Excluded(3)), (Included(3), Excluded(6))];
+/// assert_eq!(merge(ranges, MergeMode::OverlapOnly), ranges);
+/// assert_eq!(
+///     merge(ranges, MergeMode::Touching),
+///     [(Included(0), Excluded(6))],
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn merge<T: Ord + Clone>(
+    ranges: impl IntoIterator<Item = (Bound<T>, Bound<T>)>,
+    mode: MergeMode,
+) -> Vec<(Bound<T>, Bound<T>)> {
+    match mode {
+        MergeMode::OverlapOnly => merge_by(ranges, |last, range| last.overlaps(range)),
+        MergeMode::Touching => merge_overlapping(ranges),
+    }
+}
+
+/// Coalesces `ranges` of integers into the fewest ranges that cover the
+/// same values, combining ranges with no integer between them, such as
+/// `0..=2` and `3..=5`.
+///
+/// This is [`merge_overlapping`] for a discrete domain. Ranges that hold no
+/// integer, such as `(Excluded(0), Excluded(1))`, are dropped along with
+/// the empty ones, and the result is sorted by start bound.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{merge_integer_adjacent, merge_overlapping};
+/// use std::ops::Bound::*;
+///
+/// let ranges = [(Included(0), Included(2)), (Included(3), Included(5))];
+/// assert_eq!(merge_overlapping(ranges), ranges);
+/// assert_eq!(merge_integer_adjacent(ranges), [(Included(0), Included(5))]);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn merge_integer_adjacent<T: Countable>(
+    ranges: impl IntoIterator<Item = (Bound<T>, Bound<T>)>,
+) -> Vec<(Bound<T>, Bound<T>)> {
+    merge_by(
+        ranges.into_iter().filter(|range| {
+            range.min_value().is_some()
+                || range.max_value().is_some()
+                || matches!(range, (Bound::Unbounded, Bound::Unbounded))
+        }),
+        |last, range| {
+            last.union(range).is_some()
+                || last
+                    .just_above()
+                    .is_some_and(|above| range.min_value() == Some(above))
+        },
+    )
+}
+
+/// Sorts the non-empty `ranges` by start bound and folds each into the
+/// previous one when `connected` says they belong together.
+#[cfg(feature = "alloc")]
+fn merge_by<T: Ord + Clone>(
+    ranges: impl IntoIterator<Item = (Bound<T>, Bound<T>)>,
+    connected: impl Fn(&(Bound<T>, Bound<T>), &(Bound<T>, Bound<T>)) -> bool,
+) -> Vec<(Bound<T>, Bound<T>)> {
+    let mut ranges: Vec<_> = ranges
+        .into_iter()
+        .filter(|range| !range.is_empty())
+        .collect();
+    ranges.sort_by(|a, b| crate::cmp_start_bound(a.start_bound(), b.start_bound()));
+
+    let mut merged: Vec<(Bound<T>, Bound<T>)> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if connected(last, &range) => *last = last.bounding_range(&range),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::iter;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    #[cfg(feature = "alloc")]
+    use std::vec;
+
+    #[test]
+    fn overlaps_any_mixed_candidates() {
+        let ranges = [0..3, 10..20, 40..50];
+        assert!(overlaps_any(&(15..30), ranges.clone()));
+        assert!(overlaps_any(&(2..=2), ranges.clone()));
+        assert!(overlaps_any(&(45..), ranges.clone()));
+        assert!(!overlaps_any(&(20..40), ranges.clone()));
+        assert!(!overlaps_any(&(3..10), ranges.clone()));
+        assert!(!overlaps_any(&(15..15), ranges));
+        assert!(!overlaps_any(
+            &(0..100),
+            iter::empty::<core::ops::Range<i32>>()
+        ));
+    }
+
+    #[test]
+    fn overlaps_any_short_circuits() {
+        let ranges = [0..3, 10..20].into_iter().chain(iter::from_fn(|| {
+            panic!("visited a range after the overlap")
+        }));
+        assert!(overlaps_any(&(15..30), ranges));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn retain_overlapping_keeps_order() {
+        let mut ranges = vec![
+            (Included(8), Excluded(12)),
+            (Unbounded, Excluded(0)),
+            (Included(2), Included(4)),
+            (Included(5), Excluded(5)),
+            (Excluded(10), Unbounded),
+            (Included(4), Excluded(6)),
+            (Included(10), Included(20)),
+        ];
+        retain_overlapping(&mut ranges, &(Excluded(4), Included(10)));
+        assert_eq!(
+            ranges,
+            [
+                (Included(8), Excluded(12)),
+                (Included(4), Excluded(6)),
+                (Included(10), Included(20)),
+            ]
+        );
+
+        retain_overlapping(&mut ranges, &(..));
+        assert_eq!(ranges.len(), 3);
+        retain_overlapping(&mut ranges, &(7..7));
+        assert_eq!(ranges, []);
+    }
+
+    #[test]
+    fn hull_of_mixed_bounds() {
+        assert_eq!(
+            hull_of([
+                (Included(5), Excluded(8)),
+                (Unbounded, Excluded(2)),
+                (Excluded(3), Included(4)),
+            ]),
+            Some((Unbounded, Excluded(8)))
+        );
+        assert_eq!(
+            hull_of([
+                (Included(5), Included(8)),
+                (Excluded(0), Excluded(8)),
+                (Included(0), Excluded(1)),
+            ]),
+            Some((Included(0), Included(8)))
+        );
+        assert_eq!(
+            hull_of([(Included(5), Excluded(8)), (Included(20), Excluded(20))]),
+            Some((Included(5), Excluded(8)))
+        );
+        assert_eq!(
+            hull_of([(Excluded(1), Unbounded)]),
+            Some((Excluded(1), Unbounded))
+        );
+        assert_eq!(hull_of(iter::empty::<(Bound<i32>, Bound<i32>)>()), None);
+    }
+
+    #[test]
+    fn find_containing_on_boundaries() {
+        let ranges = [
+            (Included(0), Excluded(3)),
+            (Included(3), Included(5)),
+            (Excluded(5), Excluded(8)),
+            (Excluded(8), Unbounded),
+        ];
+        assert_eq!(find_containing(&ranges, &-1), None);
+        assert_eq!(find_containing(&ranges, &0), Some(0));
+        assert_eq!(find_containing(&ranges, &2), Some(0));
+        assert_eq!(find_containing(&ranges, &3), Some(1));
+        assert_eq!(find_containing(&ranges, &5), Some(1));
+        assert_eq!(find_containing(&ranges, &6), Some(2));
+        assert_eq!(find_containing(&ranges, &8), None);
+        assert_eq!(find_containing(&ranges, &i32::MAX), Some(3));
+        assert_eq!(find_containing::<i32>(&[], &0), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_overlapping_nested() {
+        assert_eq!(
+            merge_overlapping([
+                (Included(0), Excluded(10)),
+                (Included(2), Excluded(5)),
+                (Excluded(3), Included(4)),
+            ]),
+            vec![(Included(0), Excluded(10))]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_overlapping_identical() {
+        assert_eq!(
+            merge_overlapping([(Included(0), Excluded(3)), (Included(0), Excluded(3))]),
+            vec![(Included(0), Excluded(3))]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_overlapping_touching() {
+        assert_eq!(
+            merge_overlapping([(Included(3), Excluded(5)), (Included(0), Excluded(3))]),
+            vec![(Included(0), Excluded(5))]
+        );
+        assert_eq!(
+            merge_overlapping([(Included(0), Excluded(3)), (Excluded(3), Excluded(5))]),
+            vec![(Included(0), Excluded(3)), (Excluded(3), Excluded(5))]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_overlapping_disjoint() {
+        assert_eq!(
+            merge_overlapping([
+                (Included(6), Unbounded),
+                (Unbounded, Excluded(0)),
+                (Included(2), Included(4)),
+            ]),
+            vec![
+                (Unbounded, Excluded(0)),
+                (Included(2), Included(4)),
+                (Included(6), Unbounded),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_overlapping_drops_empty() {
+        assert_eq!(
+            merge_overlapping([
+                (Included(0), Excluded(2)),
+                (Included(5), Excluded(5)),
+                (Included(8), Included(7)),
+            ]),
+            vec![(Included(0), Excluded(2))]
+        );
+        assert_eq!(merge_overlapping::<i32>([]), vec![]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn is_covered_by_full_coverage() {
+        let target = (Included(0), Excluded(10));
+        assert!(is_covered_by(&target, &[(Unbounded, Unbounded)]));
+        assert!(is_covered_by(&target, &[(Included(0), Excluded(10))]));
+        assert!(is_covered_by(
+            &target,
+            &[
+                (Included(5), Excluded(10)),
+                (Included(0), Excluded(3)),
+                (Included(3), Included(5)),
+            ]
+        ));
+        assert!(is_covered_by(&(Included(5), Excluded(5)), &[]));
+        assert!(!is_covered_by(&target, &[]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn is_covered_by_one_point_gap() {
+        let target = (Included(0), Excluded(10));
+        assert!(!is_covered_by(
+            &target,
+            &[(Included(0), Excluded(5)), (Excluded(5), Excluded(10))]
+        ));
+        assert!(!is_covered_by(
+            &target,
+            &[(Included(0), Included(4)), (Included(5), Excluded(10))]
+        ));
+        // The gap only matters if it falls inside the target.
+        assert!(is_covered_by(
+            &(Included(0), Excluded(5)),
+            &[(Included(0), Excluded(5)), (Excluded(5), Excluded(10))]
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn is_covered_by_overlapping_cover_with_edge_gaps() {
+        let cover = [
+            (Included(2), Excluded(6)),
+            (Included(4), Excluded(8)),
+            (Excluded(1), Included(3)),
+        ];
+        assert!(is_covered_by(&(Excluded(1), Excluded(8)), &cover));
+        assert!(!is_covered_by(&(Included(1), Excluded(8)), &cover));
+        assert!(!is_covered_by(&(Excluded(1), Included(8)), &cover));
+        assert!(!is_covered_by(&(Unbounded, Excluded(5)), &cover));
+        assert!(!is_covered_by(&(Included(5), Unbounded), &cover));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_modes_on_overlapping_input() {
+        let ranges = [(Included(0), Excluded(4)), (Included(3), Excluded(6))];
+        for mode in [MergeMode::OverlapOnly, MergeMode::Touching] {
+            assert_eq!(
+                merge(ranges, mode),
+                vec![(Included(0), Excluded(6))],
+                "{mode:?}"
+            );
+        }
+        assert_eq!(
+            merge_integer_adjacent(ranges),
+            vec![(Included(0), Excluded(6))]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_modes_on_touching_input() {
+        let ranges = [(Included(3), Excluded(6)), (Included(0), Excluded(3))];
+        assert_eq!(
+            merge(ranges, MergeMode::OverlapOnly),
+            vec![(Included(0), Excluded(3)), (Included(3), Excluded(6))]
+        );
+        assert_eq!(
+            merge(ranges, MergeMode::Touching),
+            vec![(Included(0), Excluded(6))]
+        );
+        assert_eq!(
+            merge_integer_adjacent(ranges),
+            vec![(Included(0), Excluded(6))]
+        );
+
+        // `merge` needs only `Ord`, so it takes ranges of any ordered type.
+        let words = [
+            (Included("c"), Excluded("e")),
+            (Included("a"), Excluded("c")),
+        ];
+        assert_eq!(merge(words, MergeMode::OverlapOnly).len(), 2);
+        assert_eq!(
+            merge(words, MergeMode::Touching),
+            vec![(Included("a"), Excluded("e"))]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_modes_on_gap_of_one() {
+        let adjacent = [(Included(0), Included(2)), (Included(3), Included(5))];
+        assert_eq!(merge(adjacent, MergeMode::OverlapOnly), adjacent.to_vec());
+        assert_eq!(merge(adjacent, MergeMode::Touching), adjacent.to_vec());
+        assert_eq!(
+            merge_integer_adjacent(adjacent),
+            vec![(Included(0), Included(5))]
+        );
+
+        let excluded = [(Unbounded, Excluded(3)), (Excluded(2), Excluded(3))];
+        assert_eq!(
+            merge_integer_adjacent(excluded),
+            vec![(Unbounded, Excluded(3))]
+        );
+        let gap = [(Included(0), Excluded(3)), (Excluded(3), Unbounded)];
+        assert_eq!(merge(gap, MergeMode::Touching), gap.to_vec());
+        assert_eq!(merge_integer_adjacent(gap), gap.to_vec());
+        let gap_u8 = [(Included(0u8), Included(2)), (Included(4), Included(5))];
+        assert_eq!(merge_integer_adjacent(gap_u8), gap_u8.to_vec());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn merge_integer_adjacent_drops_ranges_without_integers() {
+        assert_eq!(
+            merge_integer_adjacent([(Included(0), Included(1)), (Excluded(5), Excluded(6))]),
+            vec![(Included(0), Included(1))]
+        );
+        assert_eq!(
+            merge_integer_adjacent([(Included(u8::MAX), Unbounded), (Included(0), Included(254))]),
+            vec![(Included(0), Unbounded)]
+        );
+        // Unbounded on one side, with no integer on the other.
+        assert_eq!(
+            merge_integer_adjacent([
+                (Excluded(u8::MAX), Unbounded),
+                (Unbounded, Excluded(0u8)),
+                (Included(3), Included(4)),
+            ]),
+            vec![(Included(3), Included(4))]
+        );
+        assert_eq!(
+            merge_integer_adjacent([(Unbounded, Unbounded), (Unbounded, Excluded(0u8))]),
+            vec![(Unbounded, Unbounded)]
+        );
+        assert_eq!(
+            merge_integer_adjacent([(Unbounded, Excluded(i32::MIN)), (Unbounded, Included(2))]),
+            vec![(Unbounded, Included(2))]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::RangeBounds;
+
+/// Everything outside a range.
+///
+/// The complement of a bounded range like `3..5` is two pieces, `..3` and
+/// `5..`, so it cannot be described by a single pair of bounds and
+/// `Complement` does not implement [`RangeBounds`]. It provides its own
+/// predicates instead, which answer for the set of values the inner range
+/// does not contain.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::Complement;
+///
+/// let outside = Complement(3..5);
+/// assert!( outside.contains(&7));
+/// assert!(!outside.contains(&4));
+/// assert!( outside.overlaps(&(4..6)));
+/// assert!(!outside.overlaps(&(3..5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Complement<R>(pub R);
+
+impl<R> Complement<R> {
+    /// Returns `true` if the inner range does not contain `item`.
+    #[must_use]
+    pub fn contains<T, U>(&self, item: &U) -> bool
+    where
+        R: RangeBounds<T>,
+        T: ?Sized + PartialOrd<U>,
+        U: ?Sized + PartialOrd<T>,
+    {
+        !self.0.contains(item)
+    }
+
+    /// Returns `true` if `other` has an element outside the inner range.
+    ///
+    /// This holds unless the inner range
+    /// [contains](RangeBounds::contains_range) all of `other`, so an
+    /// [empty](RangeBounds::is_empty) `other` never overlaps the complement.
+    #[must_use]
+    pub fn overlaps<T, O, E>(&self, other: &O) -> bool
+    where
+        R: RangeBounds<T>,
+        T: ?Sized + PartialOrd<E>,
+        E: ?Sized + PartialOrd,
+        O: RangeBounds<E>,
+    {
+        !other.is_empty() && !self.0.contains_range(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+
+    #[test]
+    fn contains() {
+        let outside = Complement(3..5);
+        assert!(outside.contains(&7));
+        assert!(outside.contains(&5));
+        assert!(outside.contains(&2));
+        assert!(!outside.contains(&4));
+        assert!(!outside.contains(&3));
+        assert!(Complement(5..5).contains(&5));
+        assert!(!Complement::<core::ops::RangeFull>(..).contains(&5));
+        assert!(Complement(0.0..1.0).contains(&f64::NAN));
+    }
+
+    #[test]
+    fn overlaps() {
+        let outside = Complement((Excluded(3), Included(5)));
+        assert!(outside.overlaps(&(0..10)));
+        assert!(outside.overlaps(&(3..4)));
+        assert!(outside.overlaps(&(5..=6)));
+        assert!(!outside.overlaps(&(4..=5)));
+        assert!(!outside.overlaps(&(Excluded(3), Excluded(5))));
+        assert!(!outside.overlaps(&(7..7)));
+        assert!(Complement(5..5).overlaps(&(5..=5)));
+        assert!(!Complement::<core::ops::RangeFull>(..).overlaps::<i32, _, _>(&(0..10)));
+        assert!(Complement((Unbounded::<i32>, Excluded(0))).overlaps(&(0..=0)));
+
+        // Half steps stand in for the reals between integer endpoints.
+        let values = || (-2..=12).map(|x| x as f64 / 2.0);
+        for start in [Included(3.0), Excluded(3.0), Unbounded] {
+            for end in [Included(4.0), Excluded(4.0), Unbounded] {
+                let outside = Complement((start, end));
+                for a in 0..=5 {
+                    for b in 0..=5 {
+                        let other = a as f64..=b as f64;
+                        let expected = values()
+                            .any(|value| other.contains(&value) && outside.contains(&value));
+                        assert_eq!(outside.overlaps(&other), expected, "{outside:?} {other:?}");
+                    }
+                }
+            }
+        }
+    }
+}
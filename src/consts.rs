@@ -0,0 +1,115 @@
+//! `const fn` range predicates for the primitive integer types.
+//!
+//! Trait methods cannot be called in `const` contexts on stable Rust, and
+//! neither can comparisons through `PartialOrd`, so [`RangeBounds`] is of no
+//! help when validating a range at compile time. These helpers instead take
+//! concrete ranges of a concrete integer type by value and compare their
+//! fields with the built-in operators, which is allowed in a `const fn`:
+//!
+//! ```
+//! use rangebounds_overlaps::consts::{range_contains_i32, range_overlaps_u8};
+//!
+//! const OK: bool = range_contains_i32(0..10, 5);
+//! const CLASH: bool = range_overlaps_u8(0..16, 8..32);
+//! assert!(OK && CLASH);
+//! ```
+//!
+//! Each helper agrees with the corresponding [`RangeBounds`] method on the
+//! same range, as long as it is not a `RangeInclusive` exhausted by
+//! iteration. The `range_inclusive_contains_*` helpers read a
+//! `RangeInclusive` through its `start()` and `end()`, which iteration does
+//! not change, while [`RangeBounds`] sees an exhausted range as empty. So
+//! after `next()` on `5..=5`, the helper still finds `5` in it but
+//! [`contains`](crate::RangeBounds::contains) does not:
+//!
+//! ```
+//! use rangebounds_overlaps::consts::range_inclusive_contains_i32;
+//! use rangebounds_overlaps::RangeBounds;
+//!
+//! let mut range = 5..=5;
+//! assert_eq!(range.next(), Some(5));
+//! assert!(range_inclusive_contains_i32(range.clone(), 5));
+//! assert!(!RangeBounds::contains(&range, &5));
+//! ```
+//!
+//! [`RangeBounds`]: crate::RangeBounds
+
+use core::ops::{Range, RangeInclusive};
+
+macro_rules! const_predicates {
+    ($($t:ty => $contains:ident, $inclusive_contains:ident, $overlaps:ident;)*) => {
+        $(
+            #[doc = "Returns `true` if `range` contains `item`, like"]
+            #[doc = "[`RangeBounds::contains`](crate::RangeBounds::contains)."]
+            pub const fn $contains(range: Range<$t>, item: $t) -> bool {
+                range.start <= item && item < range.end
+            }
+
+            #[doc = "Returns `true` if `range` contains `item`, like"]
+            #[doc = "[`RangeBounds::contains`](crate::RangeBounds::contains)."]
+            pub const fn $inclusive_contains(range: RangeInclusive<$t>, item: $t) -> bool {
+                *range.start() <= item && item <= *range.end()
+            }
+
+            #[doc = "Returns `true` if `a` and `b` have an element in common, like"]
+            #[doc = "[`RangeBounds::overlaps`](crate::RangeBounds::overlaps)."]
+            pub const fn $overlaps(a: Range<$t>, b: Range<$t>) -> bool {
+                a.start < a.end && b.start < b.end && a.start < b.end && b.start < a.end
+            }
+        )*
+    };
+}
+
+const_predicates! {
+    u8 => range_contains_u8, range_inclusive_contains_u8, range_overlaps_u8;
+    u16 => range_contains_u16, range_inclusive_contains_u16, range_overlaps_u16;
+    u32 => range_contains_u32, range_inclusive_contains_u32, range_overlaps_u32;
+    u64 => range_contains_u64, range_inclusive_contains_u64, range_overlaps_u64;
+    u128 => range_contains_u128, range_inclusive_contains_u128, range_overlaps_u128;
+    usize => range_contains_usize, range_inclusive_contains_usize, range_overlaps_usize;
+    i8 => range_contains_i8, range_inclusive_contains_i8, range_overlaps_i8;
+    i16 => range_contains_i16, range_inclusive_contains_i16, range_overlaps_i16;
+    i32 => range_contains_i32, range_inclusive_contains_i32, range_overlaps_i32;
+    i64 => range_contains_i64, range_inclusive_contains_i64, range_overlaps_i64;
+    i128 => range_contains_i128, range_inclusive_contains_i128, range_overlaps_i128;
+    isize => range_contains_isize, range_inclusive_contains_isize, range_overlaps_isize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RangeBounds;
+
+    // Evaluated at compile time.
+    const _: () = assert!(range_contains_i32(0..10, 5));
+    const _: () = assert!(!range_contains_i32(0..10, 10));
+    const _: () = assert!(range_inclusive_contains_u8(0..=10, 10));
+    const _: () = assert!(range_overlaps_usize(0..10, 9..12));
+    const _: () = assert!(!range_overlaps_usize(0..10, 10..12));
+
+    #[test]
+    fn agrees_with_trait_methods() {
+        for start in -3..=3 {
+            for end in -3..=3 {
+                for item in -4..=4 {
+                    assert_eq!(
+                        range_contains_i32(start..end, item),
+                        RangeBounds::contains(&(start..end), &item)
+                    );
+                    assert_eq!(
+                        range_inclusive_contains_i32(start..=end, item),
+                        RangeBounds::contains(&(start..=end), &item)
+                    );
+                }
+                for other_start in -3..=3 {
+                    for other_end in -3..=3 {
+                        assert_eq!(
+                            range_overlaps_i32(start..end, other_start..other_end),
+                            (start..end).overlaps(&(other_start..other_end))
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
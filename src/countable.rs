@@ -0,0 +1,451 @@
+use core::iter::FusedIterator;
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::sealed::Sealed;
+use crate::RangeBounds;
+
+/// Element types whose values can be counted and stepped through one at a
+/// time, such as the primitive integers.
+///
+/// Methods like [`RangeBounds::cardinality`](crate::RangeBounds::cardinality)
+/// that only make sense when each value has a well-defined next value are
+/// available for ranges of `Countable` types. The trait is sealed: it is
+/// implemented for every primitive integer type and for `char`, and cannot
+/// be implemented outside this crate.
+///
+/// The `char` values are the Unicode scalar values, which skip the
+/// surrogate code points `U+D800` to `U+DFFF`. Stepping and counting skip
+/// them too, so `'\u{D7FF}'` is followed by `'\u{E000}'`.
+pub trait Countable: Ord + Clone + Sealed {
+    /// The least value of the type.
+    const MIN: Self;
+
+    /// The greatest value of the type.
+    const MAX: Self;
+
+    /// Returns the value right after `self`, or `None` if `self` is the
+    /// greatest value.
+    fn successor(&self) -> Option<Self>;
+
+    /// Returns the value right before `self`, or `None` if `self` is the
+    /// least value.
+    fn predecessor(&self) -> Option<Self>;
+
+    /// Returns the number of values in `start..=end`, where `start <= end`,
+    /// or `None` if that number does not fit in a `u128`.
+    fn count_inclusive(start: &Self, end: &Self) -> Option<u128>;
+}
+
+macro_rules! impl_countable {
+    ($($t:ty)*) => {
+        $(
+            impl Sealed for $t {}
+
+            impl Countable for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count_inclusive(start: &Self, end: &Self) -> Option<u128> {
+                    (end.abs_diff(*start) as u128).checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_countable! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// The first code point after the surrogates.
+const AFTER_SURROGATES: u32 = 0xE000;
+/// The number of surrogate code points, none of which is a `char`.
+const SURROGATES: u32 = 0xE000 - 0xD800;
+
+impl Sealed for char {}
+
+impl Countable for char {
+    const MIN: Self = '\0';
+    const MAX: Self = char::MAX;
+
+    fn successor(&self) -> Option<Self> {
+        match *self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            value => char::from_u32(u32::from(value) + 1),
+        }
+    }
+
+    fn predecessor(&self) -> Option<Self> {
+        match *self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            value => char::from_u32(u32::from(value).checked_sub(1)?),
+        }
+    }
+
+    fn count_inclusive(start: &Self, end: &Self) -> Option<u128> {
+        let (start, end) = (u32::from(*start), u32::from(*end));
+        let surrogates = if start < AFTER_SURROGATES && end >= AFTER_SURROGATES {
+            SURROGATES
+        } else {
+            0
+        };
+        Some(u128::from(end - start - surrogates) + 1)
+    }
+}
+
+/// An iterator over the values of a range of [`Countable`] values.
+///
+/// This `struct` is created by [`RangeBounds::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<T> {
+    /// The first and last values not yet yielded, or `None` once exhausted.
+    remaining: Option<(T, T)>,
+}
+
+impl<T: Countable> Iter<T> {
+    pub(crate) fn new<R: ?Sized + RangeBounds<T>>(range: &R) -> Self {
+        let first = match range.start_bound() {
+            Included(start) => Some(start.clone()),
+            Excluded(start) => start.successor(),
+            Unbounded => panic!("cannot iterate over a range with an unbounded start"),
+        };
+        let last = match range.end_bound() {
+            Included(end) => Some(end.clone()),
+            Excluded(end) => end.predecessor(),
+            Unbounded => panic!("cannot iterate over a range with an unbounded end"),
+        };
+        let remaining = match (first, last) {
+            (Some(first), Some(last)) if first <= last => Some((first, last)),
+            _ => None,
+        };
+        Iter { remaining }
+    }
+}
+
+impl<T: Countable> Iterator for Iter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (first, last) = self.remaining.take()?;
+        if first < last {
+            let next = first
+                .successor()
+                .expect("a value below another has a successor");
+            self.remaining = Some((next, last));
+        }
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.remaining {
+            Some((first, last)) => match T::count_inclusive(first, last) {
+                Some(count) => match usize::try_from(count) {
+                    Ok(count) => (count, Some(count)),
+                    Err(_) => (usize::MAX, None),
+                },
+                None => (usize::MAX, None),
+            },
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<T: Countable> DoubleEndedIterator for Iter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        let (first, last) = self.remaining.take()?;
+        if first < last {
+            let next = last
+                .predecessor()
+                .expect("a value above another has a predecessor");
+            self.remaining = Some((first, next));
+        }
+        Some(last)
+    }
+}
+
+impl<T: Countable> FusedIterator for Iter<T> {}
+
+/// An iterator over consecutive sub-ranges of a range of [`Countable`]
+/// values.
+///
+/// This `struct` is created by [`RangeBounds::chunks`] and
+/// [`RangeBounds::paginate`].
+#[derive(Debug, Clone)]
+pub struct Chunks<T> {
+    /// The first and last values not yet covered by a chunk, or `None` once
+    /// exhausted.
+    remaining: Option<(T, T)>,
+    size: T,
+}
+
+impl<T: Countable + crate::CheckedAdd> Chunks<T> {
+    pub(crate) fn new<R: ?Sized + RangeBounds<T>>(range: &R, size: T) -> Self {
+        assert!(
+            T::MIN.checked_add(&size).is_some_and(|step| step > T::MIN),
+            "chunk size must be positive"
+        );
+        Chunks {
+            remaining: Iter::new(range).remaining,
+            size,
+        }
+    }
+}
+
+impl<T: Countable + crate::CheckedAdd> Iterator for Chunks<T> {
+    type Item = (Bound<T>, Bound<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, last) = self.remaining.take()?;
+        match first.checked_add(&self.size) {
+            Some(next) if next <= last => {
+                self.remaining = Some((next.clone(), last));
+                Some((Included(first), Excluded(next)))
+            }
+            _ => match last.successor() {
+                Some(end) => Some((Included(first), Excluded(end))),
+                None => Some((Included(first), Included(last))),
+            },
+        }
+    }
+}
+
+impl<T: Countable + crate::CheckedAdd> FusedIterator for Chunks<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn steps() {
+        assert_eq!(3u8.successor(), Some(4));
+        assert_eq!(u8::MAX.successor(), None);
+        assert_eq!(3i8.predecessor(), Some(2));
+        assert_eq!(i8::MIN.predecessor(), None);
+        assert_eq!(0u64.predecessor(), None);
+    }
+
+    #[test]
+    fn count_inclusive() {
+        assert_eq!(i32::count_inclusive(&-5, &5), Some(11));
+        assert_eq!(u8::count_inclusive(&0, &u8::MAX), Some(256));
+        assert_eq!(i64::count_inclusive(&i64::MIN, &i64::MAX), Some(1 << 64));
+        assert_eq!(i128::count_inclusive(&3, &3), Some(1));
+        assert_eq!(i128::count_inclusive(&i128::MIN, &i128::MAX), None);
+        assert_eq!(u128::count_inclusive(&1, &u128::MAX), Some(u128::MAX));
+    }
+
+    #[test]
+    fn iter() {
+        let collect = |range: (Bound<i32>, Bound<i32>)| range.iter().collect::<Vec<_>>();
+
+        assert_eq!(collect((Included(0), Included(3))), [0, 1, 2, 3]);
+        assert_eq!(collect((Included(0), Excluded(3))), [0, 1, 2]);
+        assert_eq!(collect((Excluded(0), Included(3))), [1, 2, 3]);
+        assert_eq!(collect((Excluded(0), Excluded(3))), [1, 2]);
+        assert_eq!(collect((Included(3), Included(3))), [3]);
+        assert_eq!(collect((Included(3), Excluded(3))), Vec::<i32>::new());
+        assert_eq!(collect((Excluded(3), Excluded(4))), Vec::<i32>::new());
+        assert_eq!(collect((Included(5), Included(3))), Vec::<i32>::new());
+        assert_eq!(
+            (Excluded(0), Included(3)).iter().rev().collect::<Vec<_>>(),
+            [3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn iter_at_type_limits() {
+        assert_eq!(
+            (u8::MAX - 1..=u8::MAX).iter().collect::<Vec<_>>(),
+            [254, 255]
+        );
+        assert_eq!((Excluded(u8::MAX), Included(u8::MAX)).iter().count(), 0);
+        assert_eq!(
+            (i8::MIN..=i8::MIN + 1).iter().rev().collect::<Vec<_>>(),
+            [-127, -128]
+        );
+        assert_eq!((u8::MIN..=u8::MAX).iter().count(), 256);
+        assert_eq!((Included(0u8), Excluded(0)).iter().next_back(), None);
+    }
+
+    #[test]
+    fn iter_size_hint() {
+        let mut iter = RangeBounds::iter(&(0..5));
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(RangeBounds::iter(&(3..3)).size_hint(), (0, Some(0)));
+        assert_eq!(
+            RangeBounds::iter(&(0..=u128::MAX)).size_hint(),
+            (usize::MAX, None)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unbounded end")]
+    fn iter_unbounded() {
+        let _ = RangeBounds::iter(&(0..));
+    }
+
+    #[test]
+    fn char_skips_surrogates() {
+        assert_eq!(('\0'..=char::MAX).cardinality(), Some(1_112_064));
+        assert_eq!(('\u{D7FF}'..='\u{E000}').cardinality(), Some(2));
+        assert_eq!(('a'..='z').cardinality(), Some(26));
+        assert_eq!(('\u{E000}'..).cardinality(), None);
+        assert_eq!(
+            ('\u{D7FE}'..='\u{E001}').iter().collect::<Vec<_>>(),
+            ['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']
+        );
+        assert_eq!(
+            ('\u{D7FE}'..='\u{E001}').iter().rev().collect::<Vec<_>>(),
+            ['\u{E001}', '\u{E000}', '\u{D7FF}', '\u{D7FE}']
+        );
+        assert_eq!(
+            (Excluded('\u{D7FF}'), Excluded('\u{E000}')).cardinality(),
+            Some(0)
+        );
+        assert_eq!(char::MAX.successor(), None);
+        assert_eq!('\0'.predecessor(), None);
+    }
+
+    #[test]
+    fn char_ranges_straddling_surrogates() {
+        let below = '\u{D000}'..'\u{E000}';
+        let above = '\u{E000}'..='\u{F000}';
+        assert!(!below.overlaps(&above));
+        assert!(below.touches(&above));
+        assert!(below.overlaps(&('\u{D7FF}'..='\u{E000}')));
+        assert!(above.overlaps(&('\u{D7FF}'..='\u{E000}')));
+        // No `char` lies strictly between U+D7FF and U+E000, but emptiness
+        // does not depend on the element type, so only the count sees it.
+        let gap = (Excluded('\u{D7FF}'), Excluded('\u{E000}'));
+        assert!(!gap.is_empty());
+        assert_eq!(gap.min_value(), None);
+    }
+
+    #[test]
+    fn chunks() {
+        let collect =
+            |range: (Bound<i32>, Bound<i32>), size| range.chunks(size).collect::<Vec<_>>();
+
+        assert_eq!(
+            collect((Included(0), Excluded(10)), 3),
+            [
+                (Included(0), Excluded(3)),
+                (Included(3), Excluded(6)),
+                (Included(6), Excluded(9)),
+                (Included(9), Excluded(10)),
+            ]
+        );
+        assert_eq!(
+            collect((Included(0), Excluded(9)), 3),
+            [
+                (Included(0), Excluded(3)),
+                (Included(3), Excluded(6)),
+                (Included(6), Excluded(9)),
+            ]
+        );
+        assert_eq!(
+            collect((Excluded(0), Included(4)), 10),
+            [(Included(1), Excluded(5))]
+        );
+        assert_eq!(
+            collect((Included(2), Excluded(7)), 5),
+            [(Included(2), Excluded(7))]
+        );
+        assert_eq!(collect((Included(3), Excluded(3)), 2), []);
+    }
+
+    #[test]
+    fn chunks_at_type_limits() {
+        assert_eq!(
+            (250u8..=u8::MAX).chunks(4).collect::<Vec<_>>(),
+            [
+                (Included(250), Excluded(254)),
+                (Included(254), Included(255))
+            ]
+        );
+        assert_eq!(
+            (Included(i8::MIN), Excluded(0))
+                .chunks(i8::MAX)
+                .collect::<Vec<_>>(),
+            [(Included(-128), Excluded(-1)), (Included(-1), Excluded(0)),]
+        );
+    }
+
+    #[test]
+    fn paginate_bounded() {
+        assert_eq!(
+            (Excluded(0), Included(25)).paginate(10).collect::<Vec<_>>(),
+            [
+                (Included(1), Excluded(11)),
+                (Included(11), Excluded(21)),
+                (Included(21), Excluded(26)),
+            ]
+        );
+        assert_eq!(
+            (0..30).paginate(10).collect::<Vec<_>>(),
+            (0..30).chunks(10).collect::<Vec<_>>()
+        );
+        assert_eq!((5..5).paginate(10).next(), None);
+    }
+
+    #[test]
+    fn paginate_unbounded() {
+        assert_eq!(
+            (1000u64..).paginate(1000).take(4).collect::<Vec<_>>(),
+            [
+                (Included(1000), Excluded(2000)),
+                (Included(2000), Excluded(3000)),
+                (Included(3000), Excluded(4000)),
+                (Included(4000), Excluded(5000)),
+            ]
+        );
+        let mut pages = (1000u64..).paginate(1000);
+        for page in 1..=100 {
+            assert_eq!(
+                pages.next(),
+                Some((Included(page * 1000), Excluded((page + 1) * 1000)))
+            );
+        }
+        assert_eq!(
+            (Excluded(-3), Unbounded)
+                .paginate(2)
+                .take(2)
+                .collect::<Vec<_>>(),
+            [(Included(-2), Excluded(0)), (Included(0), Excluded(2))]
+        );
+        // The last page includes the greatest value, and then the pages end.
+        let mut pages = (250u8..).paginate(4);
+        assert_eq!(pages.next(), Some((Included(250), Excluded(254))));
+        assert_eq!(pages.next(), Some((Included(254), Included(255))));
+        assert_eq!(pages.next(), None);
+        assert_eq!((i64::MAX..).paginate(1000).count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "unbounded start")]
+    fn paginate_unbounded_start() {
+        let _ = (..10).paginate(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be positive")]
+    fn chunks_of_zero() {
+        let _ = (0..10).chunks(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "unbounded")]
+    fn chunks_of_unbounded() {
+        let _ = (0..).chunks(3);
+    }
+}
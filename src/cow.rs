@@ -0,0 +1,134 @@
+use alloc::borrow::{Cow, ToOwned};
+use core::fmt;
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::RangeBounds;
+
+/// A range whose endpoint values are each either borrowed or owned.
+///
+/// A function that sometimes returns a range over borrowed data and
+/// sometimes builds a new one can return a `CowRange` in both cases instead
+/// of choosing between `Range<&T>` and `Range<T>`. It works with unsized
+/// values too, so a `CowRange<str>` can hold `&str` or `String` endpoints.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{CowRange, RangeBounds};
+/// use std::ops::Bound::*;
+///
+/// let keys = [String::from("apple"), String::from("melon")];
+/// let borrowed = CowRange::borrowed(Included(keys[0].as_str()), Included(&keys[1]));
+/// let owned = CowRange::<str>::owned(&(Included("kiwi"), Excluded("pear")));
+/// assert!(borrowed.overlaps(&owned));
+/// assert!(borrowed.contains_borrowed("banana"));
+/// ```
+pub struct CowRange<'a, T: ?Sized + ToOwned>(pub Bound<Cow<'a, T>>, pub Bound<Cow<'a, T>>);
+
+impl<'a, T: ?Sized + ToOwned> CowRange<'a, T> {
+    /// Returns a range borrowing its endpoint values.
+    pub fn borrowed(start: Bound<&'a T>, end: Bound<&'a T>) -> Self {
+        CowRange(start.map(Cow::Borrowed), end.map(Cow::Borrowed))
+    }
+
+    /// Returns a range owning copies of the endpoint values of `range`.
+    pub fn owned<R: ?Sized + RangeBounds<T>>(range: &R) -> Self {
+        let to_owned = |bound: Bound<&T>| bound.map(|value| Cow::Owned(value.to_owned()));
+        CowRange(to_owned(range.start_bound()), to_owned(range.end_bound()))
+    }
+
+    /// Converts every borrowed endpoint value into an owned one, so the
+    /// range no longer borrows anything.
+    pub fn into_owned(self) -> CowRange<'static, T> {
+        let into_owned = |bound: Bound<Cow<'a, T>>| {
+            bound.map(|value| Cow::<'static, T>::Owned(value.into_owned()))
+        };
+        CowRange(into_owned(self.0), into_owned(self.1))
+    }
+}
+
+impl<T: ?Sized + ToOwned> RangeBounds<T> for CowRange<'_, T> {
+    fn start_bound(&self) -> Bound<&T> {
+        match self.0 {
+            Included(ref start) => Included(start),
+            Excluded(ref start) => Excluded(start),
+            Unbounded => Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        match self.1 {
+            Included(ref end) => Included(end),
+            Excluded(ref end) => Excluded(end),
+            Unbounded => Unbounded,
+        }
+    }
+}
+
+impl<T: ?Sized + ToOwned> Clone for CowRange<'_, T> {
+    fn clone(&self) -> Self {
+        CowRange(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<T: ?Sized + ToOwned + fmt::Debug> fmt::Debug for CowRange<'_, T>
+where
+    T::Owned: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CowRange")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+impl<T: ?Sized + ToOwned + PartialEq> PartialEq for CowRange<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start_bound() == other.start_bound() && self.end_bound() == other.end_bound()
+    }
+}
+
+impl<T: ?Sized + ToOwned + Eq> Eq for CowRange<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::String;
+
+    #[test]
+    fn borrowed_and_owned_overlap() {
+        let values = [3, 8];
+        let borrowed = CowRange::borrowed(Included(&values[0]), Excluded(&values[1]));
+        let owned: CowRange<i32> = CowRange::owned(&(5..=10));
+        assert!(matches!(borrowed.0, Included(Cow::Borrowed(_))));
+        assert!(matches!(owned.1, Included(Cow::Owned(10))));
+        assert!(borrowed.overlaps(&owned));
+        assert!(owned.overlaps(&borrowed));
+        assert!(!borrowed.overlaps(&CowRange::<i32>::owned(&(8..))));
+        assert!(borrowed.overlaps(&(7..)));
+    }
+
+    #[test]
+    fn same_bounds_compare_equal() {
+        let value = 4;
+        let borrowed = CowRange::borrowed(Excluded(&value), Unbounded);
+        let owned = CowRange::owned(&(Excluded(4), Unbounded));
+        assert_eq!(borrowed, owned);
+        assert_eq!(borrowed.clone().into_owned(), owned);
+        assert_ne!(owned, CowRange::owned(&(Included(4), Unbounded)));
+    }
+
+    #[test]
+    fn unsized_values() {
+        let low = String::from("b");
+        let borrowed: CowRange<str> = CowRange::borrowed(Included(&low), Excluded("d"));
+        let owned: CowRange<str> = CowRange(Included(Cow::Owned(String::from("c"))), Unbounded);
+        assert!(borrowed.overlaps(&owned));
+        assert!(!owned.contains_borrowed("b"));
+        assert_eq!(owned.start_bound(), Included("c"));
+        let owned = borrowed.into_owned();
+        drop(low);
+        assert_eq!(owned.end_bound(), Excluded("d"));
+    }
+}
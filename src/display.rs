@@ -0,0 +1,108 @@
+use core::fmt;
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::RangeBounds;
+
+/// Formats a range in interval notation.
+///
+/// Square brackets mark included endpoints and parentheses excluded ones,
+/// and an `Unbounded` side is written as `-∞` or `∞`: `3..5` displays as
+/// `[3, 5)` and `..=5` as `(-∞, 5]`. Formatting options such as precision
+/// apply to each endpoint.
+///
+/// This `struct` is created by [`RangeBounds::display`] or
+/// [`DisplayRange::new`].
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::RangeBounds;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!((3..5).display().to_string(), "[3, 5)");
+/// assert_eq!((Excluded(0), Unbounded).display().to_string(), "(0, ∞)");
+/// assert_eq!((..=0.5).display().to_string(), "(-∞, 0.5]");
+/// assert_eq!(format!("{:.1}", (0.0..=1.0).display()), "[0.0, 1.0]");
+/// ```
+#[derive(Debug)]
+pub struct DisplayRange<'a, T: ?Sized>(pub Bound<&'a T>, pub Bound<&'a T>);
+
+impl<'a, T: ?Sized> DisplayRange<'a, T> {
+    /// Borrows the bounds of `range` for display.
+    pub fn new<R: ?Sized + RangeBounds<T>>(range: &'a R) -> Self {
+        DisplayRange(range.start_bound(), range.end_bound())
+    }
+}
+
+impl<T: ?Sized> Clone for DisplayRange<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for DisplayRange<'_, T> {}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for DisplayRange<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Included(start) => {
+                f.write_str("[")?;
+                start.fmt(f)?;
+            }
+            Excluded(start) => {
+                f.write_str("(")?;
+                start.fmt(f)?;
+            }
+            Unbounded => f.write_str("(-∞")?,
+        }
+        f.write_str(", ")?;
+        match self.1 {
+            Included(end) => {
+                end.fmt(f)?;
+                f.write_str("]")
+            }
+            Excluded(end) => {
+                end.fmt(f)?;
+                f.write_str(")")
+            }
+            Unbounded => f.write_str("∞)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn std_ranges() {
+        assert_eq!((3..5).display().to_string(), "[3, 5)");
+        assert_eq!((3..=5).display().to_string(), "[3, 5]");
+        assert_eq!((3..).display().to_string(), "[3, ∞)");
+        assert_eq!((..5).display().to_string(), "(-∞, 5)");
+        assert_eq!((..=5).display().to_string(), "(-∞, 5]");
+        assert_eq!(RangeBounds::<i32>::display(&..).to_string(), "(-∞, ∞)");
+    }
+
+    #[test]
+    fn tuple_ranges() {
+        assert_eq!((Excluded(0), Unbounded).display().to_string(), "(0, ∞)");
+        assert_eq!((Excluded(0), Included(3)).display().to_string(), "(0, 3]");
+        assert_eq!((Excluded(-1), Excluded(1)).display().to_string(), "(-1, 1)");
+        assert_eq!(
+            RangeBounds::<str>::display(&(Included("a"), Excluded("b"))).to_string(),
+            "[a, b)"
+        );
+        assert_eq!(
+            DisplayRange::<str>::new(&(Included("a"), Unbounded)).to_string(),
+            "[a, ∞)"
+        );
+    }
+
+    #[test]
+    fn formatting_options() {
+        assert_eq!(std::format!("{:.2}", (0.5..1.0).display()), "[0.50, 1.00)");
+        assert_eq!(std::format!("{:>3}", (1..=2).display()), "[  1,   2]");
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::ops::Bound;
+
+use crate::RangeBounds;
+
+/// The dyn-compatible part of [`RangeBounds`], for storing ranges of
+/// different types behind `dyn`.
+///
+/// Most [`RangeBounds`] methods are generic over the other range or the item
+/// type, so `dyn RangeBounds<T>` cannot exist. `CoreRangeBounds` is
+/// implemented for every [`RangeBounds`] type and only exposes the bounds,
+/// which is enough to build a `Box<dyn CoreRangeBounds<T>>`. That box
+/// implements [`RangeBounds`] again, so the full set of methods, such as
+/// [`overlaps`](RangeBounds::overlaps), is available on it. The methods are
+/// prefixed with `dyn_` to keep them from clashing with the
+/// [`RangeBounds`] methods they forward to. The boxed impl needs the
+/// `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use rangebounds_overlaps::{CoreRangeBounds, RangeBounds};
+/// use std::ops::Bound::*;
+///
+/// let ranges: Vec<Box<dyn CoreRangeBounds<i32>>> = vec![
+///     Box::new(0..3),
+///     Box::new((Excluded(5), Unbounded)),
+///     Box::new(..=-10),
+/// ];
+/// let hits = ranges.iter().filter(|range| range.overlaps(&(2..6))).count();
+/// assert_eq!(hits, 2);
+/// # }
+/// ```
+pub trait CoreRangeBounds<T: ?Sized> {
+    /// Returns the range's start bound, as
+    /// [`RangeBounds::start_bound`].
+    fn dyn_start_bound(&self) -> Bound<&T>;
+
+    /// Returns the range's end bound, as [`RangeBounds::end_bound`].
+    fn dyn_end_bound(&self) -> Bound<&T>;
+
+    /// Returns `true` if `item` is contained in the range, as
+    /// [`RangeBounds::contains`].
+    fn dyn_contains(&self, item: &T) -> bool
+    where
+        T: PartialOrd;
+}
+
+impl<T: ?Sized, R: ?Sized + RangeBounds<T>> CoreRangeBounds<T> for R {
+    fn dyn_start_bound(&self) -> Bound<&T> {
+        self.start_bound()
+    }
+
+    fn dyn_end_bound(&self) -> Bound<&T> {
+        self.end_bound()
+    }
+
+    fn dyn_contains(&self, item: &T) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.contains(item)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> RangeBounds<T> for Box<dyn CoreRangeBounds<T> + '_> {
+    fn start_bound(&self) -> Bound<&T> {
+        (**self).dyn_start_bound()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        (**self).dyn_end_bound()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use std::vec;
+    use std::vec::Vec;
+
+    fn mixed() -> Vec<Box<dyn CoreRangeBounds<i32>>> {
+        vec![
+            Box::new(0..3),
+            Box::new(5..=5),
+            Box::new((Excluded(7), Included(9))),
+            Box::new((Unbounded, Excluded(-4))),
+            Box::new(3..3),
+        ]
+    }
+
+    #[test]
+    fn boxed_ranges_keep_their_bounds() {
+        let ranges = mixed();
+        assert_eq!(ranges[0].start_bound(), Included(&0));
+        assert_eq!(ranges[0].end_bound(), Excluded(&3));
+        assert_eq!(ranges[2].start_bound(), Excluded(&7));
+        assert_eq!(ranges[3].start_bound(), Unbounded);
+        assert!(ranges[1].dyn_contains(&5));
+        assert!(!ranges[2].dyn_contains(&7));
+        assert!(ranges[4].is_empty());
+    }
+
+    #[test]
+    fn boxed_ranges_overlap_like_the_originals() {
+        let query = (Included(2), Excluded(8));
+        let hits: Vec<usize> = mixed()
+            .iter()
+            .enumerate()
+            .filter(|(_, range)| range.overlaps(&query))
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(hits, [0, 1, 2]);
+
+        let ranges = mixed();
+        assert!(ranges[0].overlaps(&ranges[0]));
+        assert!(!ranges[0].overlaps(&ranges[1]));
+        assert!(ranges[3].overlaps(&(..)));
+        assert!(!ranges[4].overlaps(&(..)));
+    }
+}
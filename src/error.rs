@@ -0,0 +1,41 @@
+use core::fmt;
+
+/// The error returned when a comparison between endpoint values has no
+/// answer, as with a NaN.
+///
+/// Returned by [`RangeBounds::try_overlaps`](crate::RangeBounds::try_overlaps).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Incomparable;
+
+impl fmt::Display for Incomparable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("range endpoints are not comparable")
+    }
+}
+
+impl core::error::Error for Incomparable {}
+
+/// The error returned when a [`RangeBuilder`](crate::RangeBuilder) is asked
+/// to build a range that holds no values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeError {
+    /// The start is past the end, as in `5..3`.
+    Backwards,
+    /// The start and end are equal and at least one of them is excluded, as
+    /// in `5..5`.
+    EmptyExcluded,
+    /// A bound's value cannot be compared, as with a NaN.
+    Incomparable,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RangeError::Backwards => "range start is past its end",
+            RangeError::EmptyExcluded => "range start and end are equal but not both included",
+            RangeError::Incomparable => "range endpoints are not comparable",
+        })
+    }
+}
+
+impl core::error::Error for RangeError {}
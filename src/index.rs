@@ -0,0 +1,91 @@
+use core::ops::Bound::{self, Excluded, Included};
+
+use crate::RangeBounds;
+
+/// The valid indices of a buffer of length `len`, as the range `0..len`.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{IndexRange, RangeBounds};
+///
+/// let buf = [1, 2, 3, 4];
+/// let indices = IndexRange(buf.len());
+/// assert!( indices.contains(&3));
+/// assert!(!indices.contains(&4));
+/// assert!( indices.overlaps(&(2..10)));
+/// assert!(!IndexRange(0).overlaps(&(0..1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexRange(pub usize);
+
+impl RangeBounds<usize> for IndexRange {
+    fn start_bound(&self) -> Bound<&usize> {
+        Included(&0)
+    }
+    fn end_bound(&self) -> Bound<&usize> {
+        Excluded(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::Unbounded;
+
+    #[test]
+    fn index_range() {
+        let indices = IndexRange(4);
+        assert_eq!(indices.start_bound(), Included(&0));
+        assert_eq!(indices.end_bound(), Excluded(&4));
+        assert!(indices.contains(&0));
+        assert!(indices.contains(&3));
+        assert!(!indices.contains(&4));
+        assert!(indices.overlaps(&(3..)));
+        assert!(!indices.overlaps(&(4..)));
+        assert!(indices.contains_range(&(1..=3)));
+        assert!(!indices.contains_range(&(1..=4)));
+    }
+
+    #[test]
+    fn empty_index_range() {
+        let indices = IndexRange(0);
+        assert!(indices.is_empty());
+        assert!(!indices.contains(&0));
+        assert!(!indices.overlaps(&(0..1)));
+        assert!(!indices.overlaps(&(..)));
+        assert!(!indices.overlaps(&(0usize..=0)));
+    }
+
+    #[test]
+    fn clamp_to_len_each_range_kind() {
+        assert_eq!(RangeBounds::<usize>::clamp_to_len(&.., 8), 0..8);
+        assert_eq!((3..).clamp_to_len(8), 3..8);
+        assert_eq!((..5).clamp_to_len(8), 0..5);
+        assert_eq!((..=5).clamp_to_len(8), 0..6);
+        assert_eq!((2..5).clamp_to_len(8), 2..5);
+        assert_eq!((2..=5).clamp_to_len(8), 2..6);
+        assert_eq!((Excluded(2), Excluded(5)).clamp_to_len(8), 3..5);
+        assert_eq!((Excluded(2), Unbounded).clamp_to_len(8), 3..8);
+        assert_eq!(IndexRange(5).clamp_to_len(8), 0..5);
+
+        let buf = [10, 11, 12, 13, 14];
+        for range in [(Included(1), Excluded(4)), (Unbounded, Included(2))] {
+            let clamped = range.clamp_to_len(buf.len());
+            assert_eq!(buf[clamped], buf[range]);
+        }
+    }
+
+    #[test]
+    fn clamp_to_len_saturates() {
+        assert_eq!((3..100).clamp_to_len(8), 3..8);
+        assert_eq!((..=8).clamp_to_len(8), 0..8);
+        assert_eq!((..=usize::MAX).clamp_to_len(8), 0..8);
+        assert_eq!((10..).clamp_to_len(8), 8..8);
+        assert_eq!((10..20).clamp_to_len(8), 8..8);
+        assert_eq!((Excluded(usize::MAX), Unbounded).clamp_to_len(8), 8..8);
+        assert_eq!((Included(5), Excluded(3)).clamp_to_len(8), 3..3);
+        assert_eq!((Excluded(4), Included(4)).clamp_to_len(8), 5..5);
+        assert_eq!((2..5).clamp_to_len(0), 0..0);
+    }
+}
@@ -0,0 +1,114 @@
+use core::ops::Bound;
+
+use crate::RangeBounds;
+
+/// Adapts a [`core::ops::RangeBounds`] implementation to this crate's
+/// [`RangeBounds`] trait.
+///
+/// The built-in range types implement both traits already. `StdWrapper`
+/// covers range types from other crates that only implement the standard
+/// one, so they can be used with methods like
+/// [`overlaps`](RangeBounds::overlaps).
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{RangeBounds, StdWrapper};
+///
+/// struct Week(u32);
+///
+/// impl std::ops::RangeBounds<u32> for Week {
+///     fn start_bound(&self) -> std::ops::Bound<&u32> {
+///         std::ops::Bound::Included(&self.0)
+///     }
+///
+///     fn end_bound(&self) -> std::ops::Bound<&u32> {
+///         std::ops::Bound::Unbounded
+///     }
+/// }
+///
+/// assert!(StdWrapper(Week(7)).overlaps(&(0..10)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StdWrapper<R>(pub R);
+
+impl<T: ?Sized, R: core::ops::RangeBounds<T>> RangeBounds<T> for StdWrapper<R> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.0.start_bound()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.0.end_bound()
+    }
+}
+
+/// Adapts this crate's [`RangeBounds`] trait to [`core::ops::RangeBounds`],
+/// so a range can be passed to standard APIs like
+/// [`BTreeMap::range`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.range).
+///
+/// This `struct` is created by [`RangeBounds::into_std`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StdRange<R>(pub R);
+
+impl<T: ?Sized, R: RangeBounds<T>> core::ops::RangeBounds<T> for StdRange<R> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.0.start_bound()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.0.end_bound()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use std::collections::BTreeMap;
+    use std::vec::Vec;
+
+    struct Custom(i32, i32);
+
+    impl core::ops::RangeBounds<i32> for Custom {
+        fn start_bound(&self) -> Bound<&i32> {
+            Excluded(&self.0)
+        }
+
+        fn end_bound(&self) -> Bound<&i32> {
+            Included(&self.1)
+        }
+    }
+
+    #[test]
+    fn std_wrapper() {
+        let range = StdWrapper(Custom(0, 3));
+        assert_eq!(range.start_bound(), Excluded(&0));
+        assert_eq!(range.end_bound(), Included(&3));
+        assert!(range.overlaps(&(3..5)));
+        assert!(!range.overlaps(&(..=0)));
+        assert!(StdWrapper(0..3).overlaps(&StdWrapper(Custom(2, 4))));
+    }
+
+    #[test]
+    fn into_std_with_btree_map() {
+        fn keys(range: impl core::ops::RangeBounds<i32>) -> Vec<i32> {
+            let map: BTreeMap<i32, char> = (0..10).zip('a'..).collect();
+            map.range(range).map(|(k, _)| *k).collect()
+        }
+
+        assert_eq!(
+            keys(crate::BoundRange(Excluded(2), Included(5)).into_std()),
+            [3, 4, 5]
+        );
+        assert_eq!(keys(crate::Lower(Included(8)).into_std()), [8, 9]);
+        assert_eq!(keys(crate::Upper(Excluded(2)).into_std()), [0, 1]);
+        assert_eq!(
+            keys(crate::BoundRange(Unbounded, Unbounded).into_std()),
+            (0..10).collect::<Vec<_>>()
+        );
+
+        let range = crate::BoundRange(Included(4), Excluded(6));
+        assert_eq!(keys((&range).into_std()), [4, 5]);
+        assert!(range.overlaps(&(5..)));
+    }
+}
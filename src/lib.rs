@@ -0,0 +1,4893 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+mod bound_range;
+pub mod bounds;
+mod builder;
+mod builtin;
+mod canon;
+mod collection;
+mod complement;
+pub mod consts;
+mod countable;
+#[cfg(feature = "alloc")]
+mod cow;
+mod display;
+mod dyn_range;
+mod error;
+mod ext;
+mod index;
+mod interop;
+mod macros;
+mod map_range;
+mod modular;
+pub mod net;
+mod num;
+mod one_sided;
+mod optional;
+#[cfg(feature = "alloc")]
+mod range_set;
+mod reversed;
+
+mod sealed {
+    pub trait Sealed {}
+
+    pub trait SealedRange<T: ?Sized> {}
+}
+
+#[cfg(feature = "rkyv")]
+pub use bound_range::ArchivedBoundRange;
+pub use bound_range::BoundRange;
+pub use builder::RangeBuilder;
+pub use builtin::BuiltinRange;
+pub use canon::CanonRange;
+pub use collection::{find_containing, hull_of, overlaps_any};
+#[cfg(feature = "alloc")]
+pub use collection::{
+    is_covered_by, merge, merge_integer_adjacent, merge_overlapping, retain_overlapping, MergeMode,
+};
+pub use complement::Complement;
+pub use countable::{Chunks, Countable, Iter};
+#[cfg(feature = "alloc")]
+pub use cow::CowRange;
+pub use display::DisplayRange;
+pub use dyn_range::CoreRangeBounds;
+pub use error::{Incomparable, RangeError};
+pub use ext::RangeExt;
+pub use index::IndexRange;
+pub use interop::{StdRange, StdWrapper};
+#[doc(hidden)]
+pub use macros::__assert_overlap;
+pub use map_range::MapRange;
+pub use modular::ModularRange;
+pub use num::{CheckedAdd, Float, SaturatingAdd, SaturatingSub};
+pub use one_sided::{Lower, Upper};
+pub use optional::OptionalRange;
+#[cfg(feature = "alloc")]
+pub use range_set::RangeSet;
+pub use reversed::Reversed;
+
+use core::borrow::Borrow;
+use core::cmp::{self, Ordering};
+use core::ops::{
+    Add,
+    Bound::{self, Excluded, Included, Unbounded},
+    Mul, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive, Sub,
+};
+
+/// `RangeBounds` is implemented by Rust's built-in range types, produced
+/// by range syntax like `..`, `a..`, `..b`, `..=c`, `d..e`, or `f..=g`,
+/// and by pairs of [`Bound`]s.
+///
+/// # Implementing
+///
+/// Only `start_bound` and `end_bound` need to be provided, and every other
+/// method derives its answer from them, so they must return the same bounds
+/// each time they are called. A start past the end is allowed and describes
+/// an [empty](RangeBounds::is_empty) range. Use [`BuiltinRange`] to accept
+/// only the implementations provided by this crate.
+///
+/// # Empty results
+///
+/// The set operations [`intersection`](RangeBounds::intersection),
+/// [`difference`](RangeBounds::difference),
+/// [`symmetric_difference`](RangeBounds::symmetric_difference),
+/// [`union`](RangeBounds::union) and [`gap`](RangeBounds::gap) never return
+/// an empty range: where the result would hold no elements they return
+/// `None` for it instead, so `(0..3).intersection(&(3..6))` is `None` rather
+/// than the zero-width `(Included(3), Excluded(3))`. Any range they do
+/// return can be treated as non-empty.
+pub trait RangeBounds<T: ?Sized> {
+    /// Start index bound.
+    ///
+    /// Returns the start value as a `Bound`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() {
+    /// use std::ops::Bound::*;
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((..10).start_bound(), Unbounded);
+    /// assert_eq!((3..10).start_bound(), Included(&3));
+    /// # }
+    /// ```
+    // #[stable(feature = "collections_range", since = "1.28.0")]
+    fn start_bound(&self) -> Bound<&T>;
+
+    /// End index bound.
+    ///
+    /// Returns the end value as a `Bound`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() {
+    /// use std::ops::Bound::*;
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((3..).end_bound(), Unbounded);
+    /// assert_eq!((3..10).end_bound(), Excluded(&10));
+    /// # }
+    /// ```
+    // #[stable(feature = "collections_range", since = "1.28.0")]
+    fn end_bound(&self) -> Bound<&T>;
+
+    /// Returns `true` if `item` is contained in the range.
+    ///
+    /// Each bound is compared with the bound value on the left when it is a
+    /// start and on the right when it is an end, through `T: PartialOrd<U>`
+    /// as `start <= item` and through `U: PartialOrd<T>` as `item <= end`.
+    /// Both directions are needed for that, and they match
+    /// `core::ops::RangeBounds::contains`, so the two give the same answer
+    /// even for a pair of types whose `PartialOrd` impls disagree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!( (3..5).contains(&4));
+    /// assert!(!(3..5).contains(&2));
+    ///
+    /// assert!( (0.0..1.0).contains(&0.5));
+    /// assert!(!(0.0..1.0).contains(&f32::NAN));
+    /// assert!(!(0.0..f32::NAN).contains(&0.5));
+    /// assert!(!(f32::NAN..1.0).contains(&0.5));
+    /// ```
+    // #[stable(feature = "range_contains", since = "1.35.0")]
+    #[must_use]
+    fn contains<U>(&self, item: &U) -> bool
+    where
+        T: PartialOrd<U>,
+        U: ?Sized + PartialOrd<T>,
+    {
+        (match self.start_bound() {
+            Included(start) => start <= item,
+            Excluded(start) => start < item,
+            Unbounded => true,
+        }) && (match self.end_bound() {
+            Included(end) => item <= end,
+            Excluded(end) => item < end,
+            Unbounded => true,
+        })
+    }
+
+    /// Returns `true` if `item` is contained in the range, comparing it with
+    /// the bounds' borrowed form.
+    ///
+    /// [`contains`](RangeBounds::contains) needs `T: PartialOrd<U>`, which
+    /// owned types often lack against their borrowed forms: `String` is not
+    /// `PartialOrd<str>`, so a `Range<String>` cannot `contains("m")`. Like
+    /// [`BTreeMap::range`], this method borrows each bound as a `Q` through
+    /// [`Borrow`] and compares in that type instead.
+    ///
+    /// [`BTreeMap::range`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// let range = String::from("a")..String::from("n");
+    /// assert!( range.contains_borrowed("m"));
+    /// assert!(!range.contains_borrowed("z"));
+    /// ```
+    #[must_use]
+    fn contains_borrowed<Q>(&self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + PartialOrd,
+    {
+        (match self.start_bound() {
+            Included(start) => start.borrow() <= item,
+            Excluded(start) => start.borrow() < item,
+            Unbounded => true,
+        }) && (match self.end_bound() {
+            Included(end) => item <= end.borrow(),
+            Excluded(end) => item < end.borrow(),
+            Unbounded => true,
+        })
+    }
+
+    /// Returns `true` if `item` is contained in the range, allowing for
+    /// rounding error of up to `epsilon` at the endpoints.
+    ///
+    /// Each bound is widened or narrowed by `epsilon` according to its kind:
+    /// a value less than `epsilon` outside an `Included` endpoint counts as
+    /// inside, and a value less than `epsilon` inside an `Excluded` endpoint
+    /// counts as outside. So a computed `0.30000000000000004` is in
+    /// `0.0..=0.3`, and a computed `0.9999999999999999` is not in `0.0..1.0`
+    /// for an `epsilon` of `1e-9`. A zero or negative `epsilon` gives the
+    /// same answers as [`contains`](RangeBounds::contains). An
+    /// [empty](RangeBounds::is_empty) range contains nothing, and a NaN
+    /// `item` or `epsilon` always gives `false`.
+    ///
+    /// Like [`overlaps_within`](RangeBounds::overlaps_within), this takes
+    /// [`Float`] ranges only, since the distance to an integer endpoint can
+    /// overflow. The tolerance is a plain absolute difference: the crate has
+    /// no dependencies, so this does not go through the `approx` crate's
+    /// `AbsDiffEq`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (0.0..=0.3).contains_approx(&(0.1 + 0.2), 1e-9));
+    /// assert!(!(0.0..=0.3).contains(&(0.1 + 0.2)));
+    /// assert!(!(0.0..1.0).contains_approx(&(1.0 - 1e-12), 1e-9));
+    /// assert!( (0.0..1.0).contains_approx(&0.5, 1e-9));
+    /// ```
+    #[must_use]
+    fn contains_approx(&self, item: &T, epsilon: T) -> bool
+    where
+        T: Float,
+    {
+        if item.partial_cmp(item).is_none()
+            || epsilon.partial_cmp(&epsilon).is_none()
+            || self.is_empty()
+        {
+            return false;
+        }
+        (match self.start_bound() {
+            Included(&start) => start <= *item || start - *item < epsilon,
+            Excluded(&start) => start < *item && *item - start >= epsilon,
+            Unbounded => true,
+        }) && (match self.end_bound() {
+            Included(&end) => *item <= end || *item - end < epsilon,
+            Excluded(&end) => *item < end && end - *item >= epsilon,
+            Unbounded => true,
+        })
+    }
+
+    /// Returns `true` if every item in `items` is contained in the range.
+    ///
+    /// Stops at the first item outside the range, so the remaining items
+    /// are not visited. An empty `items` is vacuously contained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (0..10).contains_all([3, 5, 9]));
+    /// assert!(!(0..10).contains_all([3, 10]));
+    /// assert!( (0..10).contains_all(Vec::<i32>::new()));
+    /// ```
+    #[must_use]
+    fn contains_all<U, I>(&self, items: I) -> bool
+    where
+        T: PartialOrd<U>,
+        U: PartialOrd<T>,
+        I: IntoIterator<Item = U>,
+    {
+        items.into_iter().all(|item| self.contains(&item))
+    }
+
+    /// Returns where `item` lies relative to the range: [`Below`] its start,
+    /// [`Inside`] it, or [`Above`] its end.
+    ///
+    /// A value equal to an `Excluded` endpoint lies outside the range, on
+    /// that endpoint's side. A range with an `Unbounded` start never reports
+    /// `Below`, and one with an `Unbounded` end never reports `Above`. The
+    /// start is checked first, so for an empty range such as `5..5` every
+    /// value is either `Below` or `Above`, and a value that does not compare
+    /// with the start at all, such as NaN, is `Below`.
+    ///
+    /// [`Below`]: PointPosition::Below
+    /// [`Inside`]: PointPosition::Inside
+    /// [`Above`]: PointPosition::Above
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::{PointPosition, RangeBounds};
+    ///
+    /// assert_eq!((3..5).locate(&2), PointPosition::Below);
+    /// assert_eq!((3..5).locate(&3), PointPosition::Inside);
+    /// assert_eq!((3..5).locate(&5), PointPosition::Above);
+    /// assert_eq!((..5).locate(&i32::MIN), PointPosition::Inside);
+    /// ```
+    #[must_use]
+    fn locate<U>(&self, item: &U) -> PointPosition
+    where
+        T: PartialOrd<U>,
+        U: ?Sized + PartialOrd<T>,
+    {
+        let after_start = match self.start_bound() {
+            Included(start) => start <= item,
+            Excluded(start) => start < item,
+            Unbounded => true,
+        };
+        if !after_start {
+            return PointPosition::Below;
+        }
+        let before_end = match self.end_bound() {
+            Included(end) => item <= end,
+            Excluded(end) => item < end,
+            Unbounded => true,
+        };
+        if before_end {
+            PointPosition::Inside
+        } else {
+            PointPosition::Above
+        }
+    }
+
+    /// Restricts `value` to the range.
+    ///
+    /// Returns `value` if the range contains it, and otherwise the bound it
+    /// lies beyond. An `Included` bound's value is the nearest contained
+    /// value, but an `Excluded` bound has no nearest contained value in a
+    /// dense domain, so its value is returned as-is even though it is not in
+    /// the range: clamping `12` into `0..10` gives `10`. `Unbounded` sides
+    /// let `value` through unchanged.
+    ///
+    /// If the range is [empty](RangeBounds::is_empty), `value` is raised to
+    /// the start and then lowered to the end, so the end bound wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((0..=10).clamp_value(5), 5);
+    /// assert_eq!((0..=10).clamp_value(-3), 0);
+    /// assert_eq!((0..=10).clamp_value(12), 10);
+    /// assert_eq!((0..10).clamp_value(12), 10);
+    /// assert_eq!((..10).clamp_value(-300), -300);
+    /// ```
+    #[must_use]
+    fn clamp_value(&self, value: T) -> T
+    where
+        T: Ord + Clone,
+    {
+        let value = match self.start_bound() {
+            Included(start) | Excluded(start) if value < *start => start.clone(),
+            _ => value,
+        };
+        match self.end_bound() {
+            Included(end) | Excluded(end) if value > *end => end.clone(),
+            _ => value,
+        }
+    }
+
+    /// Returns the length of the range, `end - start`, or `None` if either
+    /// side is `Unbounded`.
+    ///
+    /// This is the continuous measure of the range, so whether the bounds
+    /// are included makes no difference: `0..10`, `0..=10` and
+    /// `(Excluded(0), Excluded(10))` all measure `10`. It is not the number
+    /// of integers in the range. An [empty](RangeBounds::is_empty) range
+    /// measures zero, even if its start is past its end or one of its
+    /// endpoints is NaN. The zero is computed as an endpoint minus itself,
+    /// so an empty range with no finite endpoint, such as `NAN..NAN` or
+    /// `INFINITY..INFINITY`, has no zero to give and returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((0..10).measure(), Some(10));
+    /// assert_eq!((0.5..=2.0).measure(), Some(1.5));
+    /// assert_eq!((3..).measure(), None);
+    /// ```
+    #[must_use]
+    fn measure(&self) -> Option<T>
+    where
+        T: Sub<Output = T> + Clone + PartialOrd,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Included(start) | Excluded(start), Included(end) | Excluded(end)) => {
+                if self.is_empty() {
+                    [start, end]
+                        .into_iter()
+                        .map(|value| value.clone() - value.clone())
+                        .find(|zero| zero.partial_cmp(zero).is_some())
+                } else {
+                    Some(end.clone() - start.clone())
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of values in a range of integers, or `None` if the
+    /// range is unbounded.
+    ///
+    /// Unlike [`measure`](RangeBounds::measure), this counts discrete
+    /// values, so the bound kinds matter: `0..10` has 10 values, `0..=10`
+    /// has 11 and `(Excluded(0), Excluded(10))` has 9. An
+    /// [empty](RangeBounds::is_empty) range has none. `None` is also
+    /// returned if the count does not fit in a `u128`, which only happens
+    /// for the full range of `i128` or `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((0..10).cardinality(), Some(10));
+    /// assert_eq!((0..=10).cardinality(), Some(11));
+    /// assert_eq!((5..5).cardinality(), Some(0));
+    /// assert_eq!((0u8..).cardinality(), None);
+    /// ```
+    #[must_use]
+    fn cardinality(&self) -> Option<u128>
+    where
+        T: Countable,
+    {
+        let first = match self.start_bound() {
+            Included(start) => Some(start.clone()),
+            Excluded(start) => start.successor(),
+            Unbounded => return None,
+        };
+        let last = match self.end_bound() {
+            Included(end) => Some(end.clone()),
+            Excluded(end) => end.predecessor(),
+            Unbounded => return None,
+        };
+        match (first, last) {
+            (Some(first), Some(last)) if first <= last => T::count_inclusive(&first, &last),
+            _ => Some(0),
+        }
+    }
+
+    /// Returns the least value in a range of integers, or `None` if the range
+    /// is empty or has no least value.
+    ///
+    /// An `Excluded` start steps up to the next value, so `(Excluded(0),
+    /// Included(5))` has the least value `1`. A range with an `Unbounded`
+    /// start has no least value, but one with an `Unbounded` end still does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((Excluded(0), Included(5)).min_value(), Some(1));
+    /// assert_eq!((3..).min_value(), Some(3));
+    /// assert_eq!((..3).min_value(), None);
+    /// assert_eq!((3..3).min_value(), None);
+    /// ```
+    #[must_use]
+    fn min_value(&self) -> Option<T>
+    where
+        T: Countable,
+    {
+        let min = match self.start_bound() {
+            Included(start) => start.clone(),
+            Excluded(start) => start.successor()?,
+            Unbounded => return None,
+        };
+        let in_range = match self.end_bound() {
+            Included(end) => min <= *end,
+            Excluded(end) => min < *end,
+            Unbounded => true,
+        };
+        in_range.then_some(min)
+    }
+
+    /// Returns the greatest value in a range of integers, or `None` if the
+    /// range is empty or has no greatest value.
+    ///
+    /// An `Excluded` end steps down to the previous value, so `(Excluded(0),
+    /// Excluded(5))` has the greatest value `4`. A range with an `Unbounded`
+    /// end has no greatest value, but one with an `Unbounded` start still
+    /// does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((0..5).max_value(), Some(4));
+    /// assert_eq!((..=3).max_value(), Some(3));
+    /// assert_eq!((3..).max_value(), None);
+    /// assert_eq!((3..3).max_value(), None);
+    /// ```
+    #[must_use]
+    fn max_value(&self) -> Option<T>
+    where
+        T: Countable,
+    {
+        let max = match self.end_bound() {
+            Included(end) => end.clone(),
+            Excluded(end) => end.predecessor()?,
+            Unbounded => return None,
+        };
+        let in_range = match self.start_bound() {
+            Included(start) => *start <= max,
+            Excluded(start) => *start < max,
+            Unbounded => true,
+        };
+        in_range.then_some(max)
+    }
+
+    /// Returns the greatest integer below the range's start, or `None` if
+    /// the start is `Unbounded` or already the type's least value.
+    ///
+    /// An `Included` start steps down to the previous value, while an
+    /// `Excluded` start is itself the answer, so both `3..10` and
+    /// `(Excluded(2), Excluded(10))` give `2`. Only the start bound is read,
+    /// so an empty range still has a value just below it. Together with
+    /// [`just_above`](RangeBounds::just_above) this gives the nearest values
+    /// outside a range, for boundary-value tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((3..10).just_below(), Some(2));
+    /// assert_eq!((Excluded(2), Excluded(10)).just_below(), Some(2));
+    /// assert_eq!((..10).just_below(), None);
+    /// assert_eq!((0u8..10).just_below(), None);
+    /// ```
+    #[must_use]
+    fn just_below(&self) -> Option<T>
+    where
+        T: Countable,
+    {
+        match self.start_bound() {
+            Included(start) => start.predecessor(),
+            Excluded(start) => Some(start.clone()),
+            Unbounded => None,
+        }
+    }
+
+    /// Returns the least integer above the range's end, or `None` if the end
+    /// is `Unbounded` or already the type's greatest value.
+    ///
+    /// An `Excluded` end is itself the answer, so `3..10` and `..10` give
+    /// `10`, while an `Included` end steps up to the next value, so `3..=10`
+    /// gives `11`. Only the end bound is read, as in
+    /// [`just_below`](RangeBounds::just_below).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((3..10).just_above(), Some(10));
+    /// assert_eq!((..10).just_above(), Some(10));
+    /// assert_eq!((3..=10).just_above(), Some(11));
+    /// assert_eq!((3..).just_above(), None);
+    /// assert_eq!((..=u8::MAX).just_above(), None);
+    /// ```
+    #[must_use]
+    fn just_above(&self) -> Option<T>
+    where
+        T: Countable,
+    {
+        match self.end_bound() {
+            Included(end) => end.successor(),
+            Excluded(end) => Some(end.clone()),
+            Unbounded => None,
+        }
+    }
+
+    /// Returns the range of integers with an `Excluded` start rewritten as
+    /// the `Included` value after it, holding the same values.
+    ///
+    /// `(Excluded(3), Included(7))` becomes `(Included(4), Included(7))`.
+    /// `Included` and `Unbounded` starts, the end, and an `Excluded` start
+    /// on the type's greatest value, which has no value after it, are kept
+    /// as they are. Together with
+    /// [`with_exclusive_end`](RangeBounds::with_exclusive_end) this brings
+    /// integer ranges to the half-open form of `4..8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!(
+    ///     (Excluded(3), Included(7)).with_inclusive_start(),
+    ///     (Included(4), Included(7)),
+    /// );
+    /// assert_eq!(
+    ///     (Excluded(3), Included(7)).with_inclusive_start().with_exclusive_end(),
+    ///     (Included(4), Excluded(8)),
+    /// );
+    /// ```
+    #[must_use]
+    fn with_inclusive_start(&self) -> (Bound<T>, Bound<T>)
+    where
+        T: Countable,
+    {
+        let start = match self.start_bound() {
+            Excluded(start) => start.successor().map_or(Excluded(start.clone()), Included),
+            start => start.cloned(),
+        };
+        (start, self.end_bound().cloned())
+    }
+
+    /// Returns the range of integers with an `Included` end rewritten as
+    /// the `Excluded` value after it, holding the same values.
+    ///
+    /// `(Excluded(3), Included(7))` becomes `(Excluded(3), Excluded(8))`.
+    /// `Excluded` and `Unbounded` ends, the start, and an `Included` end on
+    /// the type's greatest value, which has no value after it, are kept as
+    /// they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((3..=7).with_exclusive_end(), (Included(3), Excluded(8)));
+    /// assert_eq!((3..=u8::MAX).with_exclusive_end(), (Included(3), Included(255)));
+    /// ```
+    #[must_use]
+    fn with_exclusive_end(&self) -> (Bound<T>, Bound<T>)
+    where
+        T: Countable,
+    {
+        let end = match self.end_bound() {
+            Included(end) => end.successor().map_or(Included(end.clone()), Excluded),
+            end => end.cloned(),
+        };
+        (self.start_bound().cloned(), end)
+    }
+
+    /// Returns an iterator over the values in a range of integers, in
+    /// ascending order.
+    ///
+    /// `Excluded` bounds skip their own value, so `(Excluded(0),
+    /// Included(3))` yields `1`, `2` and `3`. An
+    /// [empty](RangeBounds::is_empty) range yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either side of the range is `Unbounded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// let values: Vec<_> = (Excluded(0), Included(3)).iter().collect();
+    /// assert_eq!(values, [1, 2, 3]);
+    /// ```
+    #[must_use]
+    fn iter(&self) -> Iter<T>
+    where
+        T: Countable,
+    {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator that splits a range of integers into consecutive
+    /// half-open sub-ranges `size` values wide.
+    ///
+    /// The chunks cover the same values as the range, in ascending order,
+    /// and the last one is narrower when the range's width is not a multiple
+    /// of `size`. A range of at most `size` values comes back as a single
+    /// chunk, and an [empty](RangeBounds::is_empty) range yields nothing. A
+    /// final chunk that ends at the type's greatest value has an `Included`
+    /// end, since there is no value past it to exclude.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either side of the range is `Unbounded`, or if `size` is
+    /// not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// let chunks: Vec<_> = (0..10).chunks(3).collect();
+    /// assert_eq!(
+    ///     chunks,
+    ///     [
+    ///         (Included(0), Excluded(3)),
+    ///         (Included(3), Excluded(6)),
+    ///         (Included(6), Excluded(9)),
+    ///         (Included(9), Excluded(10)),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    fn chunks(&self, size: T) -> Chunks<T>
+    where
+        T: Countable + CheckedAdd,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Returns an iterator over consecutive pages of `page_size` values,
+    /// like [`chunks`](RangeBounds::chunks) but allowing an `Unbounded` end.
+    ///
+    /// Pages are produced lazily, so a range such as `1000..` can be paged
+    /// through with [`take`](Iterator::take) or by stopping at a cursor
+    /// without ever naming an end. A bounded range yields the same pages as
+    /// [`chunks`](RangeBounds::chunks).
+    ///
+    /// The iterator is always finite. Every integer type has a greatest
+    /// value, so an `Unbounded` end is paged up to `T::MAX` and stops after
+    /// the page holding it; for wide types like `u64` that page is never
+    /// reached in practice. This is also why the pages are
+    /// `(Bound<T>, Bound<T>)` pairs rather than `Range<T>`: a page is
+    /// normally `Included(start)..Excluded(start + page_size)`, but the last
+    /// page before `T::MAX` must end at `Included(T::MAX)`, and a `Range<T>`
+    /// cannot hold the greatest value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is `Unbounded`, or if `page_size`
+    /// is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// let pages: Vec<_> = (1000u64..).paginate(1000).take(3).collect();
+    /// assert_eq!(
+    ///     pages,
+    ///     [
+    ///         (Included(1000), Excluded(2000)),
+    ///         (Included(2000), Excluded(3000)),
+    ///         (Included(3000), Excluded(4000)),
+    ///     ],
+    /// );
+    /// assert_eq!((0..25).paginate(10).count(), 3);
+    /// ```
+    #[must_use]
+    fn paginate(&self, page_size: T) -> Chunks<T>
+    where
+        T: Countable + CheckedAdd,
+    {
+        let end = match self.end_bound() {
+            Unbounded => Included(T::MAX),
+            end => end.cloned(),
+        };
+        Chunks::new(&(self.start_bound().cloned(), end), page_size)
+    }
+
+    /// Returns `true` if the range has a start bound, so it is not
+    /// `Unbounded` below.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (3..5).is_bounded_below());
+    /// assert!( (3..).is_bounded_below());
+    /// assert!(!(..5).is_bounded_below());
+    /// ```
+    #[must_use]
+    fn is_bounded_below(&self) -> bool {
+        !matches!(self.start_bound(), Unbounded)
+    }
+
+    /// Returns `true` if the range has an end bound, so it is not
+    /// `Unbounded` above.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (3..5).is_bounded_above());
+    /// assert!(!(3..).is_bounded_above());
+    /// assert!( (..5).is_bounded_above());
+    /// ```
+    #[must_use]
+    fn is_bounded_above(&self) -> bool {
+        !matches!(self.end_bound(), Unbounded)
+    }
+
+    /// Returns `true` if neither side of the range is `Unbounded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (3..5).is_bounded());
+    /// assert!(!(3..).is_bounded());
+    /// assert!(!RangeBounds::<i32>::is_bounded(&..));
+    /// ```
+    #[must_use]
+    fn is_bounded(&self) -> bool {
+        self.is_bounded_below() && self.is_bounded_above()
+    }
+
+    /// Returns `true` if the range contains no elements.
+    ///
+    /// A range is empty when no value can satisfy both of its bounds: its
+    /// start is past its end, or both bounds sit on the same value and at
+    /// least one of them excludes it. A range whose bounds both include the
+    /// same value contains exactly that value and is not empty.
+    ///
+    /// A bound on a value that is not comparable with itself, such as
+    /// `f64::NAN`, is satisfied by no value, so a range with such a bound is
+    /// empty even if its other side is `Unbounded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert!( (Included(3), Excluded(3)).is_empty());
+    /// assert!( (Excluded(5), Excluded(5)).is_empty());
+    /// assert!( (Excluded(5), Included(5)).is_empty());
+    /// assert!(!(Included(5), Included(5)).is_empty());
+    /// assert!( (Included(5), Included(3)).is_empty());
+    /// assert!(!(Unbounded, Excluded(3)).is_empty());
+    /// assert!( (Included(f64::NAN), Unbounded).is_empty());
+    /// ```
+    #[must_use]
+    fn is_empty(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        !holds_values(self.start_bound(), self.end_bound())
+    }
+
+    /// Returns the range's bounds as an owned pair, or `None` if the range is
+    /// [empty](RangeBounds::is_empty).
+    ///
+    /// Backward ranges such as `5..3`, and ranges such as `5..5` or
+    /// `(Excluded(5), Included(5))` whose bounds shut out their only value,
+    /// all match nothing and collapse to `None`. Every other range already
+    /// has a canonical set of bounds in a continuous domain and is returned
+    /// as is. Excluded bounds are not rewritten to their integer neighbors,
+    /// so `(Excluded(2), Excluded(5))` stays as it is rather than becoming
+    /// `3..=4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((3..5).normalize(), Some((Included(3), Excluded(5))));
+    /// assert_eq!((5..5).normalize(), None);
+    /// assert_eq!((Excluded(5), Included(5)).normalize(), None);
+    /// ```
+    #[must_use]
+    fn normalize(&self) -> Option<(Bound<T>, Bound<T>)>
+    where
+        T: Clone + PartialOrd,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            Some((self.start_bound().cloned(), self.end_bound().cloned()))
+        }
+    }
+
+    /// Returns the only value in the range if its bounds are both
+    /// `Included` and equal, as in `5..=5`.
+    ///
+    /// Only the bounds are inspected, with no knowledge of the element
+    /// type's domain. Over the integers `5..6` or `(Excluded(4),
+    /// Excluded(6))` also hold just the value 5, but they return `None`,
+    /// because over the reals they hold infinitely many values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((5..=5).is_single_point(), Some(&5));
+    /// assert_eq!((5..6).is_single_point(), None);
+    /// assert_eq!((5..5).is_single_point(), None);
+    /// ```
+    #[must_use]
+    fn is_single_point(&self) -> Option<&T>
+    where
+        T: PartialEq,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Included(start), Included(end)) if start == end => Some(start),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if there exists an element present in both ranges.
+    ///
+    /// An [empty](RangeBounds::is_empty) range has no elements to share, so
+    /// it never overlaps anything. That includes a range with a NaN
+    /// endpoint, which contains no values. A single-point range `x..=x`
+    /// overlaps exactly the ranges that [contain](RangeBounds::contains)
+    /// `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (3..5).overlaps(&(1..4)));
+    /// assert!(!(3..5).overlaps(&(5..7)));
+    /// assert!(!(5..5).overlaps(&(0..10)));
+    /// assert!(!(0.0..1.0).overlaps(&(f32::NAN..2.0)));
+    /// ```
+    ///
+    #[must_use]
+    fn overlaps<O, E>(&self, other: &O) -> bool
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        // The cross comparisons come first, so disjoint ranges are ruled out
+        // after one or two comparisons. For two bounded ranges every step
+        // takes the first arm of its match, straight to a comparison; a
+        // separate four-way match on the bounds up front measured slower in
+        // `benches/ranges.rs`.
+        starts_before_end(self.start_bound(), other.end_bound())
+            && starts_before_end(other.start_bound(), self.end_bound())
+            && holds_values(self.start_bound(), self.end_bound())
+            && holds_values(other.start_bound(), other.end_bound())
+    }
+
+    /// Returns whether the ranges [overlap](RangeBounds::overlaps), or
+    /// `Err(Incomparable)` if any comparison between their endpoints has no
+    /// answer.
+    ///
+    /// `overlaps` treats a range with a NaN endpoint as empty and answers
+    /// `false`. This method instead reports the ambiguity. Every endpoint
+    /// value must be comparable with itself and every start with every end,
+    /// even where the answer would not depend on the comparison, as when
+    /// one range is backward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::{Incomparable, RangeBounds};
+    ///
+    /// assert_eq!((0.0..1.0).try_overlaps(&(0.5..2.0)), Ok(true));
+    /// assert_eq!((0.0..1.0).try_overlaps(&(1.0..2.0)), Ok(false));
+    /// assert_eq!((0.0..1.0).try_overlaps(&(f64::NAN..2.0)), Err(Incomparable));
+    /// ```
+    fn try_overlaps<O, E>(&self, other: &O) -> Result<bool, Incomparable>
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        if !is_comparable(self.start_bound())
+            || !is_comparable(self.end_bound())
+            || !is_comparable(other.start_bound())
+            || !is_comparable(other.end_bound())
+        {
+            return Err(Incomparable);
+        }
+        let checks = [
+            try_starts_before_end(self.start_bound(), self.end_bound()),
+            try_starts_before_end(other.start_bound(), other.end_bound()),
+            try_starts_before_end(self.start_bound(), other.end_bound()),
+            try_starts_before_end(other.start_bound(), self.end_bound()),
+        ];
+        checks
+            .into_iter()
+            .try_fold(true, |all, check| Ok(check? && all))
+    }
+
+    /// Returns `true` if the ranges overlap or the gap between them is
+    /// smaller than `epsilon`.
+    ///
+    /// This is a tolerance check for values that carry rounding error, such
+    /// as floating-point coordinates: two ranges that only miss each other by
+    /// a rounding error still count as overlapping. The gap is measured
+    /// between the end of the lower range and the start of the higher one,
+    /// whichever order the ranges are given in. Touching ranges have a gap of
+    /// zero, so they overlap within any positive `epsilon`, while a zero or
+    /// negative `epsilon` admits only ranges that truly overlap. Empty ranges,
+    /// including those with a NaN endpoint, never overlap, and a NaN
+    /// `epsilon` always gives `false`.
+    ///
+    /// Only [`Float`] ranges are supported: the gap between two integer
+    /// ranges can be too large to compute without overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (0.0..1.0).overlaps_within(&(1.0000001..2.0), 1e-5));
+    /// assert!(!(0.0..1.0).overlaps_within(&(1.1..2.0), 1e-5));
+    /// assert!( (1.0000001..2.0).overlaps_within(&(0.0..1.0), 1e-5));
+    /// ```
+    #[must_use]
+    fn overlaps_within<O>(&self, other: &O, epsilon: T) -> bool
+    where
+        T: Float,
+        O: RangeBounds<T>,
+    {
+        if epsilon.partial_cmp(&epsilon).is_none() || self.is_empty() || other.is_empty() {
+            return false;
+        }
+        if self.overlaps(other) {
+            return true;
+        }
+        let (end, start) = if self.is_before(other) {
+            (self.end_bound(), other.start_bound())
+        } else {
+            (other.end_bound(), self.start_bound())
+        };
+        match (end, start) {
+            // Touching ranges are compared against zero directly, since the
+            // difference of two equal infinities is NaN.
+            (Included(end) | Excluded(end), Included(start) | Excluded(start)) => {
+                if start <= end {
+                    T::ZERO < epsilon
+                } else {
+                    *start - *end < epsilon
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if no element is present in both ranges.
+    ///
+    /// An [empty](RangeBounds::is_empty) range has no elements, so it is
+    /// disjoint from every range, including itself and other empty ranges.
+    /// With that convention `is_disjoint` is always the negation of
+    /// [`overlaps`](RangeBounds::overlaps).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (3..5).is_disjoint(&(5..7)));
+    /// assert!(!(3..5).is_disjoint(&(1..4)));
+    /// assert!( (5..5).is_disjoint(&(0..10)));
+    /// assert!( (5..5).is_disjoint(&(5..5)));
+    /// ```
+    #[must_use]
+    fn is_disjoint<O, E>(&self, other: &O) -> bool
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        !self.overlaps(other)
+    }
+
+    /// Returns `true` if every element of the range is less than every
+    /// element of `other`.
+    ///
+    /// Ranges that [touch](RangeBounds::touches) count as ordered, since
+    /// they share no element: `0..3` is before `3..5`. A range is not before
+    /// one it overlaps, even at a single point, so `0..3` is not before
+    /// `(Included(2), Excluded(5))`. Both ranges must be non-empty; an
+    /// [empty](RangeBounds::is_empty) range is neither before nor after any
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (0..3).is_before(&(3..5)));
+    /// assert!(!(0..3).is_before(&(2..5)));
+    /// assert!(!(3..5).is_before(&(0..3)));
+    /// ```
+    #[must_use]
+    fn is_before<O, E>(&self, other: &O) -> bool
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        !self.is_empty()
+            && !other.is_empty()
+            && !starts_before_end(other.start_bound(), self.end_bound())
+    }
+
+    /// Returns `true` if every element of the range is greater than every
+    /// element of `other`.
+    ///
+    /// This is [`is_before`](RangeBounds::is_before) with the ranges
+    /// swapped, so touching ranges count as ordered and empty ranges never
+    /// do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (3..5).is_after(&(0..3)));
+    /// assert!(!(2..5).is_after(&(0..3)));
+    /// ```
+    #[must_use]
+    fn is_after<O, E>(&self, other: &O) -> bool
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        !self.is_empty()
+            && !other.is_empty()
+            && !starts_before_end(self.start_bound(), other.end_bound())
+    }
+
+    /// Returns `true` if the ranges do not overlap but leave no gap between
+    /// them.
+    ///
+    /// Two ranges touch when one ends at the value the other starts at and
+    /// exactly one of them includes that value, as with `0..3` and `3..5`.
+    /// If both exclude the shared value, as with `0..3` and
+    /// `(Excluded(3), Excluded(5))`, that value lies between them.
+    ///
+    /// This method assumes a dense domain where there are always more values
+    /// between two distinct ones: `0..=3` and `4..5` do not touch, even
+    /// though no integer lies between them. [Empty](RangeBounds::is_empty)
+    /// ranges touch nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert!( (0..3).touches(&(3..5)));
+    /// assert!( (3..5).touches(&(0..3)));
+    /// assert!(!(0..3).touches(&(4..5)));
+    /// assert!(!(0..3).touches(&(Excluded(3), Excluded(5))));
+    /// assert!(!(0..=3).touches(&(4..5)));
+    /// assert!(!(0..=3).touches(&(3..5)));
+    /// ```
+    #[must_use]
+    fn touches<O, E>(&self, other: &O) -> bool
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        !self.is_empty()
+            && !other.is_empty()
+            && (meets(self.end_bound(), other.start_bound())
+                || meets(other.end_bound(), self.start_bound()))
+    }
+
+    /// Classifies how the range relates to `other` in Allen's interval
+    /// algebra.
+    ///
+    /// The ranges are compared by their bounds, so `Unbounded` sides are
+    /// equal to each other and lie beyond every bounded value: a range that
+    /// is unbounded below can never be [`After`](IntervalRelation::After) or
+    /// [`During`](IntervalRelation::During) another range.
+    /// [`Meets`](IntervalRelation::Meets) is the relation of ranges that
+    /// [touch](RangeBounds::touches); ranges sharing a single endpoint value
+    /// overlap.
+    ///
+    /// Returns `None` if either range is [empty](RangeBounds::is_empty), or
+    /// if their bounds are not comparable, such as when one is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::{IntervalRelation, RangeBounds};
+    ///
+    /// assert_eq!((0..3).relation(&(5..8)), Some(IntervalRelation::Before));
+    /// assert_eq!((0..3).relation(&(3..8)), Some(IntervalRelation::Meets));
+    /// assert_eq!((0..5).relation(&(3..8)), Some(IntervalRelation::Overlaps));
+    /// assert_eq!((3..5).relation(&(0..8)), Some(IntervalRelation::During));
+    /// assert_eq!((..5).relation(&(3..8)), Some(IntervalRelation::Overlaps));
+    /// assert_eq!((5..5).relation(&(3..8)), None);
+    /// ```
+    #[must_use]
+    fn relation<O, E>(&self, other: &O) -> Option<IntervalRelation>
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        use IntervalRelation::*;
+
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let start = partial_cmp_start_bound(self.start_bound(), other.start_bound())?;
+        let end = partial_cmp_end_bound(self.end_bound(), other.end_bound())?;
+        if !self.overlaps(other) {
+            return Some(match (start, self.touches(other)) {
+                (Ordering::Less, false) => Before,
+                (Ordering::Less, true) => Meets,
+                (_, true) => MetBy,
+                (_, false) => After,
+            });
+        }
+        Some(match (start, end) {
+            (Ordering::Less, Ordering::Less) => Overlaps,
+            (Ordering::Less, Ordering::Equal) => FinishedBy,
+            (Ordering::Less, Ordering::Greater) => Contains,
+            (Ordering::Equal, Ordering::Less) => Starts,
+            (Ordering::Equal, Ordering::Equal) => Equals,
+            (Ordering::Equal, Ordering::Greater) => StartedBy,
+            (Ordering::Greater, Ordering::Less) => During,
+            (Ordering::Greater, Ordering::Equal) => Finishes,
+            (Ordering::Greater, Ordering::Greater) => OverlappedBy,
+        })
+    }
+
+    /// Returns `true` if every element of `other` is contained in the range.
+    ///
+    /// The ranges are compared by their bounds rather than by probing
+    /// values, so this makes no assumption about the element type beyond
+    /// its ordering: `0..10` encloses `0..=9`, but `0..=9` does not enclose
+    /// `0..10` since values between `9` and `10` may exist. An `Unbounded`
+    /// side encloses any bound on that side, so `..` encloses every range.
+    /// Every range encloses an [empty](RangeBounds::is_empty) range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert!( (0..10).contains_range(&(2..5)));
+    /// assert!(!(0..10).contains_range(&(5..12)));
+    /// assert!( (0..10).contains_range(&(0..=9)));
+    /// assert!(!(0..=9).contains_range(&(0..10)));
+    /// assert!( (3..).contains_range(&(5..)));
+    /// ```
+    #[must_use]
+    fn contains_range<O, E>(&self, other: &O) -> bool
+    where
+        T: PartialOrd<E>,
+        E: ?Sized + PartialOrd,
+        O: RangeBounds<E>,
+    {
+        other.is_empty()
+            || (start_encloses(self.start_bound(), other.start_bound())
+                && end_encloses(self.end_bound(), other.end_bound()))
+    }
+
+    /// Compares the ranges as sets: `Less` if the range is a proper subset of
+    /// `other`, `Greater` if it is a proper superset, `Equal` if they hold
+    /// the same elements, and `None` if neither
+    /// [contains](RangeBounds::contains_range) the other.
+    ///
+    /// This is the partial order of a lattice of ranges. Ranges that are
+    /// disjoint or only partly overlap are incomparable. All
+    /// [empty](RangeBounds::is_empty) ranges are the same empty set, so they
+    /// compare `Equal` with each other and `Less` than any other range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!((2..5).partial_cmp_by_inclusion(&(0..10)), Some(Ordering::Less));
+    /// assert_eq!((0..).partial_cmp_by_inclusion(&(0..10)), Some(Ordering::Greater));
+    /// assert_eq!((0..=9).partial_cmp_by_inclusion(&(0..=9)), Some(Ordering::Equal));
+    /// assert_eq!((0..5).partial_cmp_by_inclusion(&(3..8)), None);
+    /// ```
+    #[must_use]
+    fn partial_cmp_by_inclusion<O, E>(&self, other: &O) -> Option<Ordering>
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        match (self.contains_range(other), other.contains_range(&self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Greater),
+            (false, true) => Some(Ordering::Less),
+            (false, false) => None,
+        }
+    }
+
+    /// Returns `true` if the two ranges hold exactly the same elements,
+    /// however their bounds are written.
+    ///
+    /// Unlike `==` on bound pairs, this compares the sets the ranges
+    /// describe, so all [empty](RangeBounds::is_empty) ranges are the same,
+    /// whatever their bounds. Elements are treated as dense, so `0..5` and
+    /// `0..=4` are different sets even for integers; compare
+    /// [`CanonRange`]s for integer sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert!( (0..5).same_set(&(Included(0), Excluded(5))));
+    /// assert!( (0..0).same_set(&(5..5)));
+    /// assert!(!(0..5).same_set(&(0..=5)));
+    /// ```
+    #[must_use]
+    fn same_set<O, E>(&self, other: &O) -> bool
+    where
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        self.contains_range(other) && other.contains_range(&self)
+    }
+
+    /// Returns the range of elements lying strictly between two disjoint
+    /// ranges, or `None` if they overlap or touch.
+    ///
+    /// The gap runs from the end of the lower range to the start of the
+    /// higher one, with each bound's inclusivity flipped: a value excluded
+    /// from a range is the first value of the gap, while a value included in
+    /// a range is not part of it. So `(0..3).gap(&(5..8))` is
+    /// `(Included(3), Excluded(5))`, and the gap between `0..3` and
+    /// `(Excluded(3), Excluded(5))` is the single point `3`. The result does
+    /// not depend on the order of the operands. [Empty](RangeBounds::is_empty)
+    /// ranges have no position, so there is no gap next to them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((0..3).gap(&(5..8)), Some((Included(3), Excluded(5))));
+    /// assert_eq!((5..8).gap(&(0..=3)), Some((Excluded(3), Excluded(5))));
+    /// assert_eq!((0..3).gap(&(3..8)), None);
+    /// ```
+    #[must_use]
+    fn gap<O>(&self, other: &O) -> Option<(Bound<T>, Bound<T>)>
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        if self.is_empty() || other.is_empty() || self.overlaps(other) || self.touches(other) {
+            return None;
+        }
+        let (end, start) = match cmp_start_bound(self.start_bound(), other.start_bound()) {
+            Ordering::Less => (self.end_bound(), other.start_bound()),
+            _ => (other.end_bound(), self.start_bound()),
+        };
+        Some((flip(end).cloned(), flip(start).cloned()))
+    }
+
+    /// Returns the elements of the range that are not in `other`.
+    ///
+    /// Removing `other` leaves at most two fragments: the part of the range
+    /// below `other` and the part above it, each `None` if there is nothing
+    /// left on that side.
+    ///
+    /// - If the ranges do not overlap, the range is returned unchanged as
+    ///   the first fragment.
+    /// - If `other` covers the start or the end of the range, one fragment
+    ///   remains on the other side.
+    /// - If `other` lies inside the range, it cuts a hole and leaves both
+    ///   fragments.
+    /// - If `other` encloses the range, nothing remains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!(
+    ///     (0..10).difference(&(3..5)),
+    ///     (Some((Included(0), Excluded(3))), Some((Included(5), Excluded(10)))),
+    /// );
+    /// assert_eq!((0..10).difference(&(5..)), (Some((Included(0), Excluded(5))), None));
+    /// assert_eq!((0..10).difference(&(..15)), (None, None));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[must_use]
+    fn difference<O>(
+        &self,
+        other: &O,
+    ) -> (Option<(Bound<T>, Bound<T>)>, Option<(Bound<T>, Bound<T>)>)
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        if self.is_empty() {
+            return (None, None);
+        }
+        if !self.overlaps(other) {
+            return (
+                Some((self.start_bound().cloned(), self.end_bound().cloned())),
+                None,
+            );
+        }
+        let below = match cmp_start_bound(self.start_bound(), other.start_bound()) {
+            Ordering::Less => Some((
+                self.start_bound().cloned(),
+                flip(other.start_bound()).cloned(),
+            )),
+            _ => None,
+        };
+        let above = match cmp_end_bound(self.end_bound(), other.end_bound()) {
+            Ordering::Greater => {
+                Some((flip(other.end_bound()).cloned(), self.end_bound().cloned()))
+            }
+            _ => None,
+        };
+        (below, above)
+    }
+
+    /// Returns the parts of `domain` that the range does not cover.
+    ///
+    /// This is `domain.difference(self)`: at most two fragments, the part of
+    /// `domain` below the range and the part above it. Each fragment's outer
+    /// bound is the matching bound of `domain`, and its cut bound is the
+    /// range's bound with its kind flipped, so cutting `3..=5` out of `0..10`
+    /// leaves `0..3` and `(Excluded(5), Excluded(10))`.
+    ///
+    /// - If the range touches or passes one edge of `domain`, only the
+    ///   fragment on the other side remains.
+    /// - If the range covers all of `domain`, nothing remains.
+    /// - If the range misses `domain`, `domain` is returned unchanged as the
+    ///   first fragment, whichever side the range is on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!(
+    ///     (3..5).complement_within(&(0..10)),
+    ///     (Some((Included(0), Excluded(3))), Some((Included(5), Excluded(10)))),
+    /// );
+    /// assert_eq!((..5).complement_within(&(0..10)), (None, Some((Included(5), Excluded(10)))));
+    /// assert_eq!((..).complement_within(&(0..10)), (None, None));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[must_use]
+    fn complement_within<D>(
+        &self,
+        domain: &D,
+    ) -> (Option<(Bound<T>, Bound<T>)>, Option<(Bound<T>, Bound<T>)>)
+    where
+        T: Clone + Ord,
+        D: RangeBounds<T>,
+    {
+        domain.difference(&self)
+    }
+
+    /// Returns the elements covered by exactly one of the two ranges.
+    ///
+    /// The result has a lower and a higher part, each of which may be
+    /// missing:
+    ///
+    /// - If the ranges are disjoint, both are returned unchanged, the lower
+    ///   one first.
+    /// - If they overlap, the lower part runs from the lesser start to the
+    ///   greater start and the higher part from the lesser end to the greater
+    ///   end. A part is `None` if both ranges share that bound, so equal
+    ///   ranges give `(None, None)`.
+    /// - If one range encloses the other, this makes the two parts the
+    ///   fragments of the enclosing range on either side of the enclosed
+    ///   one.
+    /// - If one range is [empty](RangeBounds::is_empty), the other is the
+    ///   lower part and the higher part is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!(
+    ///     (0..10).symmetric_difference(&(3..5)),
+    ///     (Some((Included(0), Excluded(3))), Some((Included(5), Excluded(10)))),
+    /// );
+    /// assert_eq!(
+    ///     (0..5).symmetric_difference(&(3..10)),
+    ///     (Some((Included(0), Excluded(3))), Some((Included(5), Excluded(10)))),
+    /// );
+    /// assert_eq!(
+    ///     (0..5).symmetric_difference(&(0..10)),
+    ///     (None, Some((Included(5), Excluded(10)))),
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[must_use]
+    fn symmetric_difference<O>(
+        &self,
+        other: &O,
+    ) -> (Option<(Bound<T>, Bound<T>)>, Option<(Bound<T>, Bound<T>)>)
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        let this = || (self.start_bound().cloned(), self.end_bound().cloned());
+        let that = || (other.start_bound().cloned(), other.end_bound().cloned());
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => return (None, None),
+            (true, false) => return (Some(that()), None),
+            (false, true) => return (Some(this()), None),
+            (false, false) => {}
+        }
+
+        let starts = cmp_start_bound(self.start_bound(), other.start_bound());
+        if !self.overlaps(other) {
+            return match starts {
+                Ordering::Greater => (Some(that()), Some(this())),
+                _ => (Some(this()), Some(that())),
+            };
+        }
+        let below = match starts {
+            Ordering::Less => Some((
+                self.start_bound().cloned(),
+                flip(other.start_bound()).cloned(),
+            )),
+            Ordering::Greater => Some((
+                other.start_bound().cloned(),
+                flip(self.start_bound()).cloned(),
+            )),
+            Ordering::Equal => None,
+        };
+        let above = match cmp_end_bound(self.end_bound(), other.end_bound()) {
+            Ordering::Less => Some((flip(self.end_bound()).cloned(), other.end_bound().cloned())),
+            Ordering::Greater => {
+                Some((flip(other.end_bound()).cloned(), self.end_bound().cloned()))
+            }
+            Ordering::Equal => None,
+        };
+        (below, above)
+    }
+
+    /// Returns the range of elements present in both ranges, or `None` if
+    /// they do not [overlap](RangeBounds::overlaps).
+    ///
+    /// The result starts at the greater of the two starts and ends at the
+    /// lesser of the two ends. When both ranges share an endpoint value it
+    /// is `Included` only if both ranges include it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((0..10).intersection(&(5..15)), Some((Included(5), Excluded(10))));
+    /// assert_eq!((0..=5).intersection(&(5..)), Some((Included(5), Included(5))));
+    /// assert_eq!((0..5).intersection(&(5..10)), None);
+    /// ```
+    #[must_use]
+    fn intersection<O>(&self, other: &O) -> Option<(Bound<T>, Bound<T>)>
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let start = cmp::max_by(self.start_bound(), other.start_bound(), |a, b| {
+            cmp_start_bound(*a, *b)
+        });
+        let end = cmp::min_by(self.end_bound(), other.end_bound(), |a, b| {
+            cmp_end_bound(*a, *b)
+        });
+        Some((start.cloned(), end.cloned()))
+    }
+
+    /// Restricts the range so it never extends past `window`, or returns
+    /// `None` if the two do not [overlap](RangeBounds::overlaps).
+    ///
+    /// Each side keeps the range's own bound where it is tighter than the
+    /// window's, and takes the window's otherwise. This is the
+    /// [intersection](RangeBounds::intersection), named for bounding a
+    /// query to a valid domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((-5..100).clamp_range(&(0..50)), Some((Included(0), Excluded(50))));
+    /// assert_eq!((10..=20).clamp_range(&(0..50)), Some((Included(10), Included(20))));
+    /// assert_eq!((60..70).clamp_range(&(0..50)), None);
+    /// ```
+    #[must_use]
+    fn clamp_range<O>(&self, window: &O) -> Option<(Bound<T>, Bound<T>)>
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        self.intersection(window)
+    }
+
+    /// Resolves a range of indices against a buffer of length `len`,
+    /// returning the concrete `start..end` it selects, clamped so that
+    /// `start <= end <= len`.
+    ///
+    /// Bounds resolve the way slicing resolves them: an `Unbounded` start is
+    /// `0`, an `Unbounded` end is `len`, an `Included` end `e` is `e + 1` and
+    /// an `Excluded` start `s` is `s + 1`. Where slicing would panic, this
+    /// saturates instead: an end past `len` becomes `len`, and a start past
+    /// the end becomes the end, so the result selects nothing. The result
+    /// can always be used to slice a buffer of length `len`.
+    ///
+    /// A `RangeFull` implements `RangeBounds<T>` for every `T`, so call this
+    /// method on it as `RangeBounds::<usize>::clamp_to_len(&.., len)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// let buf = b"hello world";
+    /// assert_eq!((6..).clamp_to_len(buf.len()), 6..11);
+    /// assert_eq!((..=4).clamp_to_len(buf.len()), 0..5);
+    /// assert_eq!((3..100).clamp_to_len(buf.len()), 3..11);
+    /// assert_eq!(&buf[(20..).clamp_to_len(buf.len())], b"");
+    /// ```
+    #[must_use]
+    fn clamp_to_len(&self, len: usize) -> Range<usize>
+    where
+        Self: RangeBounds<usize>,
+    {
+        let end = match RangeBounds::<usize>::end_bound(self) {
+            Included(&end) => end.saturating_add(1),
+            Excluded(&end) => end,
+            Unbounded => len,
+        }
+        .min(len);
+        let start = match RangeBounds::<usize>::start_bound(self) {
+            Included(&start) => start,
+            Excluded(&start) => start.saturating_add(1),
+            Unbounded => 0,
+        }
+        .min(end);
+        start..end
+    }
+
+    /// Saturates the range to `domain`, replacing each side that reaches past
+    /// the domain, including an `Unbounded` one, with the domain's bound.
+    /// Returns `None` if the two do not overlap.
+    ///
+    /// An unbounded-below query therefore starts where the domain starts.
+    /// This is the same range as [`clamp_range`](RangeBounds::clamp_range)
+    /// produces, named for turning open-ended user input into a finite
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::{self, *};
+    ///
+    /// let domain = 0..100;
+    /// let everything: (Bound<i32>, Bound<i32>) = (Unbounded, Unbounded);
+    /// assert_eq!(everything.saturating_within(&domain), Some((Included(0), Excluded(100))));
+    /// assert_eq!((50..).saturating_within(&domain), Some((Included(50), Excluded(100))));
+    /// assert_eq!((..0).saturating_within(&domain), None);
+    /// ```
+    #[must_use]
+    fn saturating_within<O>(&self, domain: &O) -> Option<(Bound<T>, Bound<T>)>
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        self.intersection(domain)
+    }
+
+    /// Returns the [measure](RangeBounds::measure) of the
+    /// [intersection](RangeBounds::intersection) of the two ranges, or `None`
+    /// if they do not overlap.
+    ///
+    /// Ranges that share a single point, such as `0..=5` and `5..10`,
+    /// overlap by `Some(0)`. `None` is also returned if the intersection is
+    /// unbounded, as for `0..` and `5..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!((0..10).overlap_amount(&(5..15)), Some(5));
+    /// assert_eq!((0..=5).overlap_amount(&(5..10)), Some(0));
+    /// assert_eq!((0..5).overlap_amount(&(5..10)), None);
+    /// ```
+    #[must_use]
+    fn overlap_amount<O>(&self, other: &O) -> Option<T>
+    where
+        T: Sub<Output = T> + Ord + Clone,
+        O: RangeBounds<T>,
+    {
+        self.intersection(other)?.measure()
+    }
+
+    /// Returns the range covering both ranges, or `None` if there is a gap
+    /// between them.
+    ///
+    /// Ranges that overlap or are adjacent, such as `0..3` and `3..5`, merge
+    /// into a single range. Ranges that meet at a point excluded from both,
+    /// such as `0..3` and `(Excluded(3), Excluded(5))`, leave that point
+    /// uncovered and return `None`.
+    ///
+    /// The result starts at the lesser of the two starts and ends at the
+    /// greater of the two ends. An `Unbounded` start or end on either side is
+    /// therefore `Unbounded` in the union, and when both ranges share an
+    /// endpoint value it is `Included` if either range includes it. An empty
+    /// range contributes nothing, so the union with it is the other range,
+    /// and the union of two empty ranges is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((0..3).union(&(3..5)), Some((Included(0), Excluded(5))));
+    /// assert_eq!((0..3).union(&(2..)), Some((Included(0), Unbounded)));
+    /// assert_eq!((0..3).union(&(4..5)), None);
+    /// ```
+    #[must_use]
+    fn union<O>(&self, other: &O) -> Option<(Bound<T>, Bound<T>)>
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => return None,
+            (true, false) => {
+                return Some((other.start_bound().cloned(), other.end_bound().cloned()))
+            }
+            (false, true) => return Some((self.start_bound().cloned(), self.end_bound().cloned())),
+            (false, false) => {}
+        }
+        if !(connects(self.start_bound(), other.end_bound())
+            && connects(other.start_bound(), self.end_bound()))
+        {
+            return None;
+        }
+        let start = cmp::min_by(self.start_bound(), other.start_bound(), |a, b| {
+            cmp_start_bound(*a, *b)
+        });
+        let end = cmp::max_by(self.end_bound(), other.end_bound(), |a, b| {
+            cmp_end_bound(*a, *b)
+        });
+        Some((start.cloned(), end.cloned()))
+    }
+
+    /// Returns the smallest range enclosing both ranges, spanning any gap
+    /// between them.
+    ///
+    /// Unlike [`union`](RangeBounds::union), this always succeeds: the
+    /// result starts at the lesser of the two starts and ends at the greater
+    /// of the two ends, so `0..3` and `7..9` give `0..9`. An `Unbounded`
+    /// start or end on either side is `Unbounded` in the result. An
+    /// [empty](RangeBounds::is_empty) range has no elements to enclose, so
+    /// the other range is returned; if both are empty, so is the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((0..3).bounding_range(&(7..9)), (Included(0), Excluded(9)));
+    /// assert_eq!((0..3).bounding_range(&(..=1)), (Unbounded, Excluded(3)));
+    /// ```
+    #[must_use]
+    fn bounding_range<O>(&self, other: &O) -> (Bound<T>, Bound<T>)
+    where
+        T: Clone + Ord,
+        O: RangeBounds<T>,
+    {
+        if self.is_empty() {
+            return (other.start_bound().cloned(), other.end_bound().cloned());
+        }
+        if other.is_empty() {
+            return (self.start_bound().cloned(), self.end_bound().cloned());
+        }
+        let start = cmp::min_by(self.start_bound(), other.start_bound(), |a, b| {
+            cmp_start_bound(*a, *b)
+        });
+        let end = cmp::max_by(self.end_bound(), other.end_bound(), |a, b| {
+            cmp_end_bound(*a, *b)
+        });
+        (start.cloned(), end.cloned())
+    }
+
+    /// Returns the range with `f` applied to each bounded endpoint, keeping
+    /// every bound's kind.
+    ///
+    /// `Included` and `Excluded` bounds stay as they are around the new
+    /// value, and `Unbounded` sides stay `Unbounded`. The result only
+    /// describes the image of the range if `f` preserves order, as unit
+    /// conversions do.
+    ///
+    /// The std range types are iterators, so `(3..10).map(f)` calls
+    /// [`Iterator::map`]; call this method as `RangeBounds::map(&(3..10), f)`
+    /// on them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// let millis = RangeBounds::map(&(3..10), |&secs| secs * 1000);
+    /// assert_eq!(millis, (Included(3000), Excluded(10000)));
+    /// assert_eq!((Excluded(2), Unbounded).map(|&x| x * 2), (Excluded(4), Unbounded));
+    /// ```
+    #[must_use]
+    fn map<U, F>(&self, f: F) -> (Bound<U>, Bound<U>)
+    where
+        F: Fn(&T) -> U,
+    {
+        (self.start_bound().map(&f), self.end_bound().map(&f))
+    }
+
+    /// Returns the range moved by `delta`, adding it to each bounded
+    /// endpoint.
+    ///
+    /// Bound kinds are kept and `Unbounded` sides stay `Unbounded`, so
+    /// `(3..10).offset(5)` is `8..15`. Overflow behaves as `+` does for `T`:
+    /// for integers it panics in debug builds and wraps in release builds.
+    /// Use [`checked_offset`](RangeBounds::checked_offset) to detect it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((3..10).offset(5), (Included(8), Excluded(15)));
+    /// assert_eq!((3..).offset(-5), (Included(-2), Unbounded));
+    /// ```
+    #[must_use]
+    fn offset(&self, delta: T) -> (Bound<T>, Bound<T>)
+    where
+        T: Add<Output = T> + Clone,
+    {
+        let shift = |value: &T| value.clone() + delta.clone();
+        (self.start_bound().map(shift), self.end_bound().map(shift))
+    }
+
+    /// Returns the range moved by `delta` like
+    /// [`offset`](RangeBounds::offset), or `None` if moving either endpoint
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((3u8..10).checked_offset(5), Some((Included(8), Excluded(15))));
+    /// assert_eq!((3u8..=250).checked_offset(10), None);
+    /// assert_eq!((3u8..).checked_offset(250), Some((Included(253), Unbounded)));
+    /// ```
+    #[must_use]
+    fn checked_offset(&self, delta: T) -> Option<(Bound<T>, Bound<T>)>
+    where
+        T: CheckedAdd,
+    {
+        let shift = |bound: Bound<&T>| match bound {
+            Included(value) => value.checked_add(&delta).map(Included),
+            Excluded(value) => value.checked_add(&delta).map(Excluded),
+            Unbounded => Some(Unbounded),
+        };
+        Some((shift(self.start_bound())?, shift(self.end_bound())?))
+    }
+
+    /// Returns the range moved up by `delta` like
+    /// [`offset`](RangeBounds::offset), pinning any endpoint that would pass
+    /// the type's greatest value to that value.
+    ///
+    /// Bound kinds are kept, so a range pushed entirely past the limit
+    /// collapses to an empty or single-point range at it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((3u8..10).saturating_offset(5), (Included(8), Excluded(15)));
+    /// assert_eq!((200u8..=250).saturating_offset(10), (Included(210), Included(255)));
+    /// ```
+    #[must_use]
+    fn saturating_offset(&self, delta: T) -> (Bound<T>, Bound<T>)
+    where
+        T: SaturatingAdd,
+    {
+        let shift = |value: &T| value.saturating_add(&delta);
+        (self.start_bound().map(shift), self.end_bound().map(shift))
+    }
+
+    /// Returns the range moved down by `delta`, pinning any endpoint that
+    /// would pass the type's least value to that value.
+    ///
+    /// This is the way to shift a range of a type that has no negative
+    /// values, such as [`Duration`](core::time::Duration) or `u32`, towards
+    /// zero: the endpoints stop at zero instead of panicking. Bound kinds are
+    /// kept, so a range pushed entirely past the limit collapses to an empty
+    /// or single-point range at it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    /// use std::time::Duration;
+    ///
+    /// let window = Duration::from_secs(1)..Duration::from_secs(15);
+    /// assert_eq!(
+    ///     window.saturating_offset_sub(Duration::from_secs(10)),
+    ///     (Included(Duration::ZERO), Excluded(Duration::from_secs(5))),
+    /// );
+    /// ```
+    #[must_use]
+    fn saturating_offset_sub(&self, delta: T) -> (Bound<T>, Bound<T>)
+    where
+        T: SaturatingSub,
+    {
+        let shift = |value: &T| value.saturating_sub(&delta);
+        (self.start_bound().map(shift), self.end_bound().map(shift))
+    }
+
+    /// Returns the range stretched or shrunk around zero by `factor`,
+    /// multiplying each bounded endpoint.
+    ///
+    /// Zero is taken to be `T::default()`, as it is for the numeric
+    /// primitives. A negative factor mirrors the range, so the start and end
+    /// swap places along with their bound kinds: `(2..6).scale(-1)` is
+    /// `(Excluded(-6), Included(-2))`, and an `Unbounded` start becomes an
+    /// `Unbounded` end. A zero factor sends every bounded endpoint to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((2..6).scale(3), (Included(6), Excluded(18)));
+    /// assert_eq!((2..6).scale(-1), (Excluded(-6), Included(-2)));
+    /// assert_eq!((..6).scale(-2), (Excluded(-12), Unbounded));
+    /// ```
+    #[must_use]
+    fn scale(&self, factor: T) -> (Bound<T>, Bound<T>)
+    where
+        T: Mul<Output = T> + Clone + PartialOrd + Default,
+    {
+        let stretch = |value: &T| value.clone() * factor.clone();
+        let start = self.start_bound().map(stretch);
+        let end = self.end_bound().map(stretch);
+        if factor < T::default() {
+            (end, start)
+        } else {
+            (start, end)
+        }
+    }
+
+    /// Splits the range at `pivot` into the part below it and the part from it
+    /// onward.
+    ///
+    /// The pivot belongs to the upper half: the lower half ends at
+    /// `Excluded(pivot)` and the upper half starts at `Included(pivot)`,
+    /// unless the range's own bounds are tighter. Together the halves cover
+    /// exactly the original range. If the pivot lies before the range the
+    /// lower half is empty, and if it lies past the range the upper half is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// let (below, above) = (0..10).split_at(5);
+    /// assert_eq!(below, (Included(0), Excluded(5)));
+    /// assert_eq!(above, (Included(5), Excluded(10)));
+    ///
+    /// let (below, above) = (0..10).split_at(20);
+    /// assert_eq!(below, (Included(0), Excluded(10)));
+    /// assert!(above.is_empty());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[must_use]
+    fn split_at(&self, pivot: T) -> ((Bound<T>, Bound<T>), (Bound<T>, Bound<T>))
+    where
+        T: Clone + Ord,
+    {
+        let below_end = cmp::min_by(self.end_bound(), Excluded(&pivot), |a, b| {
+            cmp_end_bound(*a, *b)
+        });
+        let above_start = cmp::max_by(self.start_bound(), Included(&pivot), |a, b| {
+            cmp_start_bound(*a, *b)
+        });
+        (
+            (self.start_bound().cloned(), below_end.cloned()),
+            (above_start.cloned(), self.end_bound().cloned()),
+        )
+    }
+
+    /// Returns a [`DisplayRange`] that formats the range in interval
+    /// notation, such as `[3, 5)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// assert_eq!(format!("{}", (..=5).display()), "(-∞, 5]");
+    /// ```
+    #[must_use]
+    fn display(&self) -> DisplayRange<'_, T> {
+        DisplayRange::new(self)
+    }
+
+    /// Wraps the range in a [`Reversed`], which holds the same values but
+    /// iterates over them in descending order.
+    ///
+    /// The bounds are not swapped: `(3..10).reversed()` still starts at `3`
+    /// and ends before `10`, and every predicate gives the same answer as on
+    /// `3..10`. Only [`Reversed::iter`] sees the direction, yielding `9`
+    /// down to `3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    ///
+    /// let values: Vec<_> = (3..10).reversed().iter().collect();
+    /// assert_eq!(values, [9, 8, 7, 6, 5, 4, 3]);
+    /// ```
+    #[must_use]
+    fn reversed(self) -> Reversed<Self>
+    where
+        Self: Sized,
+    {
+        Reversed(self)
+    }
+
+    /// Wraps the range in a [`StdRange`], which implements the standard
+    /// library's [`core::ops::RangeBounds`] trait.
+    ///
+    /// Use this to pass a range that only implements this crate's trait,
+    /// such as a [`BoundRange`], to standard APIs. Call it on a reference to
+    /// keep the original range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::{BoundRange, RangeBounds};
+    /// use std::collections::BTreeSet;
+    /// use std::ops::Bound::*;
+    ///
+    /// let set: BTreeSet<i32> = (0..10).collect();
+    /// let range = BoundRange(Excluded(2), Included(5));
+    /// let values: Vec<_> = set.range((&range).into_std()).collect();
+    /// assert_eq!(values, [&3, &4, &5]);
+    /// ```
+    #[must_use]
+    fn into_std(self) -> StdRange<Self>
+    where
+        Self: Sized,
+    {
+        StdRange(self)
+    }
+
+    /// Returns the range as a [`Range`] if its bounds are `Included` and
+    /// `Excluded`, as in `3..5`, or `None` otherwise.
+    ///
+    /// This and the other `as_range_*` methods convert a computed range back
+    /// to the std type of the same shape without changing its bounds, so
+    /// `(Excluded(2), Excluded(5))` is not a `Range` even though over the
+    /// integers it holds the same values as `3..5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::RangeBounds;
+    /// use std::ops::Bound::*;
+    ///
+    /// assert_eq!((Included(3), Excluded(5)).as_range(), Some(3..5));
+    /// assert_eq!((Excluded(2), Excluded(5)).as_range(), None);
+    /// let overlap = (3..5).intersection(&(0..4)).unwrap();
+    /// assert_eq!(overlap.as_range(), Some(3..4));
+    /// ```
+    #[must_use]
+    fn as_range(&self) -> Option<Range<T>>
+    where
+        T: Clone,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Included(start), Excluded(end)) => Some(start.clone()..end.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the range as a [`RangeInclusive`] if both of its bounds are
+    /// `Included`, as in `3..=5`, or `None` otherwise.
+    #[must_use]
+    fn as_range_inclusive(&self) -> Option<RangeInclusive<T>>
+    where
+        T: Clone,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Included(start), Included(end)) => Some(start.clone()..=end.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the range as a [`RangeFrom`] if its start is `Included` and
+    /// its end `Unbounded`, as in `3..`, or `None` otherwise.
+    #[must_use]
+    fn as_range_from(&self) -> Option<RangeFrom<T>>
+    where
+        T: Clone,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Included(start), Unbounded) => Some(start.clone()..),
+            _ => None,
+        }
+    }
+
+    /// Returns the range as a [`RangeTo`] if its start is `Unbounded` and
+    /// its end `Excluded`, as in `..5`, or `None` otherwise.
+    #[must_use]
+    fn as_range_to(&self) -> Option<RangeTo<T>>
+    where
+        T: Clone,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Unbounded, Excluded(end)) => Some(..end.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the range as a [`RangeToInclusive`] if its start is
+    /// `Unbounded` and its end `Included`, as in `..=5`, or `None` otherwise.
+    #[must_use]
+    fn as_range_to_inclusive(&self) -> Option<RangeToInclusive<T>>
+    where
+        T: Clone,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Unbounded, Included(end)) => Some(..=end.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// One of the thirteen relations of Allen's interval algebra, describing how
+/// a range `a` lies relative to a range `b`.
+///
+/// Returned by [`RangeBounds::relation`]. Each relation has an inverse that
+/// describes `b` relative to `a`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalRelation {
+    /// `a` ends before `b` starts, with a gap between them.
+    Before,
+    /// `a` ends where `b` starts, with no gap and no shared element.
+    Meets,
+    /// `a` starts before `b` and ends inside it.
+    Overlaps,
+    /// `a` starts with `b` and ends before it.
+    Starts,
+    /// `a` starts after `b` and ends before it.
+    During,
+    /// `a` starts after `b` and ends with it.
+    Finishes,
+    /// `a` and `b` have the same bounds.
+    Equals,
+    /// `a` starts with `b` and ends after it.
+    StartedBy,
+    /// `a` starts before `b` and ends after it.
+    Contains,
+    /// `a` starts before `b` and ends with it.
+    FinishedBy,
+    /// `a` starts inside `b` and ends after it.
+    OverlappedBy,
+    /// `a` starts where `b` ends, with no gap and no shared element.
+    MetBy,
+    /// `a` starts after `b` ends, with a gap between them.
+    After,
+}
+
+impl IntervalRelation {
+    /// Returns the relation of `b` to `a` given the relation of `a` to `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rangebounds_overlaps::IntervalRelation;
+    ///
+    /// assert_eq!(IntervalRelation::Before.inverse(), IntervalRelation::After);
+    /// assert_eq!(IntervalRelation::Equals.inverse(), IntervalRelation::Equals);
+    /// ```
+    pub fn inverse(self) -> Self {
+        use IntervalRelation::*;
+
+        match self {
+            Before => After,
+            Meets => MetBy,
+            Overlaps => OverlappedBy,
+            Starts => StartedBy,
+            During => Contains,
+            Finishes => FinishedBy,
+            Equals => Equals,
+            StartedBy => Starts,
+            Contains => During,
+            FinishedBy => Finishes,
+            OverlappedBy => Overlaps,
+            MetBy => Meets,
+            After => Before,
+        }
+    }
+}
+
+/// Where a value lies relative to a range.
+///
+/// Returned by [`RangeBounds::locate`]. The variants are ordered from the
+/// lowest values to the highest, so `Below < Inside < Above`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PointPosition {
+    /// The value is before the range's start.
+    Below,
+    /// The value is in the range.
+    Inside,
+    /// The value is past the range's end.
+    Above,
+}
+
+/// Returns `true` if a range starting at `start` and a range ending at `end`
+/// can have an element in common, i.e. `start` is not past `end`.
+fn starts_before_end<S, E>(start: Bound<&S>, end: Bound<&E>) -> bool
+where
+    S: ?Sized + PartialOrd<E>,
+    E: ?Sized,
+{
+    match (start, end) {
+        (Included(start), Included(end)) => start <= end,
+        (Included(start), Excluded(end))
+        | (Excluded(start), Included(end))
+        | (Excluded(start), Excluded(end)) => start < end,
+        (Unbounded, _) | (_, Unbounded) => true,
+    }
+}
+
+/// Returns `true` if the range from `start` to `end` holds a value, i.e. it
+/// is not [empty](RangeBounds::is_empty).
+///
+/// Between two values this is a single comparison, which already fails for
+/// a NaN. Only a value facing `Unbounded` is compared with itself to rule
+/// out NaN.
+fn holds_values<T: ?Sized + PartialOrd>(start: Bound<&T>, end: Bound<&T>) -> bool {
+    match (start, end) {
+        (Included(_) | Excluded(_), Included(_) | Excluded(_)) => starts_before_end(start, end),
+        (Included(value) | Excluded(value), Unbounded)
+        | (Unbounded, Included(value) | Excluded(value)) => value.partial_cmp(value).is_some(),
+        (Unbounded, Unbounded) => true,
+    }
+}
+
+/// Like [`starts_before_end`], but reports an incomparable pair of values
+/// instead of answering `false`.
+fn try_starts_before_end<S, E>(start: Bound<&S>, end: Bound<&E>) -> Result<bool, Incomparable>
+where
+    S: ?Sized + PartialOrd<E>,
+    E: ?Sized,
+{
+    let ordering = match (start, end) {
+        (Unbounded, _) | (_, Unbounded) => return Ok(true),
+        (Included(start) | Excluded(start), Included(end) | Excluded(end)) => {
+            start.partial_cmp(end).ok_or(Incomparable)?
+        }
+    };
+    Ok(match (start, end) {
+        (Included(_), Included(_)) => ordering != Ordering::Greater,
+        _ => ordering == Ordering::Less,
+    })
+}
+
+/// Returns `false` if the bound's value is not comparable with itself, as
+/// with a NaN, so that no value can satisfy the bound.
+fn is_comparable<T: ?Sized + PartialOrd>(bound: Bound<&T>) -> bool {
+    match bound {
+        Included(value) | Excluded(value) => value.partial_cmp(value).is_some(),
+        Unbounded => true,
+    }
+}
+
+/// Returns `true` if a range ending at `end` and a range starting at `start`
+/// share their endpoint value without both including it or both excluding it.
+fn meets<S, E>(end: Bound<&S>, start: Bound<&E>) -> bool
+where
+    S: ?Sized + PartialEq<E>,
+    E: ?Sized,
+{
+    match (end, start) {
+        (Included(end), Excluded(start)) | (Excluded(end), Included(start)) => end == start,
+        _ => false,
+    }
+}
+
+/// Returns `true` if a range starting at `outer` begins no later than a range
+/// starting at `inner`.
+fn start_encloses<S, E>(outer: Bound<&S>, inner: Bound<&E>) -> bool
+where
+    S: ?Sized + PartialOrd<E>,
+    E: ?Sized,
+{
+    match (outer, inner) {
+        (Unbounded, _) => true,
+        (_, Unbounded) => false,
+        (Excluded(outer), Included(inner)) => outer < inner,
+        (Included(outer), Included(inner))
+        | (Included(outer), Excluded(inner))
+        | (Excluded(outer), Excluded(inner)) => outer <= inner,
+    }
+}
+
+/// Returns `true` if a range ending at `outer` finishes no earlier than a
+/// range ending at `inner`.
+fn end_encloses<S, E>(outer: Bound<&S>, inner: Bound<&E>) -> bool
+where
+    S: ?Sized + PartialOrd<E>,
+    E: ?Sized,
+{
+    match (outer, inner) {
+        (Unbounded, _) => true,
+        (_, Unbounded) => false,
+        (Excluded(outer), Included(inner)) => outer > inner,
+        (Included(outer), Included(inner))
+        | (Included(outer), Excluded(inner))
+        | (Excluded(outer), Excluded(inner)) => outer >= inner,
+    }
+}
+
+/// Returns `true` if a range starting at `start` and a range ending at `end`
+/// leave no element between them uncovered, i.e. they overlap or are
+/// adjacent.
+fn connects<T: ?Sized + Ord>(start: Bound<&T>, end: Bound<&T>) -> bool {
+    match (start, end) {
+        (Excluded(start), Excluded(end)) => start < end,
+        (Included(start), Included(end))
+        | (Included(start), Excluded(end))
+        | (Excluded(start), Included(end)) => start <= end,
+        (Unbounded, _) | (_, Unbounded) => true,
+    }
+}
+
+/// Turns an included bound into an excluded one and vice versa, so that it
+/// bounds the elements on the other side of its value.
+fn flip<T>(bound: Bound<T>) -> Bound<T> {
+    match bound {
+        Included(value) => Excluded(value),
+        Excluded(value) => Included(value),
+        Unbounded => Unbounded,
+    }
+}
+
+/// Orders two start bounds by where their ranges begin, or returns `None` if
+/// their values are not comparable.
+fn partial_cmp_start_bound<S, E>(a: Bound<&S>, b: Bound<&E>) -> Option<Ordering>
+where
+    S: ?Sized + PartialOrd<E>,
+    E: ?Sized,
+{
+    match (a, b) {
+        (Unbounded, Unbounded) => Some(Ordering::Equal),
+        (Unbounded, _) => Some(Ordering::Less),
+        (_, Unbounded) => Some(Ordering::Greater),
+        (Included(a), Included(b)) | (Excluded(a), Excluded(b)) => a.partial_cmp(b),
+        (Included(a), Excluded(b)) => a.partial_cmp(b).map(|o| o.then(Ordering::Less)),
+        (Excluded(a), Included(b)) => a.partial_cmp(b).map(|o| o.then(Ordering::Greater)),
+    }
+}
+
+/// Orders two end bounds by where their ranges finish, or returns `None` if
+/// their values are not comparable.
+fn partial_cmp_end_bound<S, E>(a: Bound<&S>, b: Bound<&E>) -> Option<Ordering>
+where
+    S: ?Sized + PartialOrd<E>,
+    E: ?Sized,
+{
+    match (a, b) {
+        (Unbounded, Unbounded) => Some(Ordering::Equal),
+        (Unbounded, _) => Some(Ordering::Greater),
+        (_, Unbounded) => Some(Ordering::Less),
+        (Included(a), Included(b)) | (Excluded(a), Excluded(b)) => a.partial_cmp(b),
+        (Included(a), Excluded(b)) => a.partial_cmp(b).map(|o| o.then(Ordering::Greater)),
+        (Excluded(a), Included(b)) => a.partial_cmp(b).map(|o| o.then(Ordering::Less)),
+    }
+}
+
+/// Orders two start bounds by where their ranges begin.
+///
+/// An `Unbounded` start comes before every other start. For starts on the
+/// same value, `Included` comes before `Excluded`, since a range that
+/// includes the value begins earlier than one that only starts after it.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::cmp_start_bound;
+/// use std::cmp::Ordering;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(cmp_start_bound(Included(&5), Excluded(&5)), Ordering::Less);
+/// assert_eq!(cmp_start_bound(Excluded(&4), Included(&5)), Ordering::Less);
+/// assert_eq!(cmp_start_bound(Unbounded, Included(&i32::MIN)), Ordering::Less);
+/// ```
+pub fn cmp_start_bound<T: ?Sized + Ord>(a: Bound<&T>, b: Bound<&T>) -> Ordering {
+    match (a, b) {
+        (Unbounded, Unbounded) => Ordering::Equal,
+        (Unbounded, _) => Ordering::Less,
+        (_, Unbounded) => Ordering::Greater,
+        (Included(a), Included(b)) | (Excluded(a), Excluded(b)) => a.cmp(b),
+        (Included(a), Excluded(b)) => a.cmp(b).then(Ordering::Less),
+        (Excluded(a), Included(b)) => a.cmp(b).then(Ordering::Greater),
+    }
+}
+
+/// Orders two end bounds by where their ranges finish.
+///
+/// An `Unbounded` end comes after every other end. For ends on the same
+/// value, `Excluded` comes before `Included`, since a range that excludes
+/// the value finishes earlier than one that includes it.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::cmp_end_bound;
+/// use std::cmp::Ordering;
+/// use std::ops::Bound::*;
+///
+/// assert_eq!(cmp_end_bound(Included(&5), Excluded(&5)), Ordering::Greater);
+/// assert_eq!(cmp_end_bound(Excluded(&6), Included(&5)), Ordering::Greater);
+/// assert_eq!(cmp_end_bound(Unbounded, Included(&i32::MAX)), Ordering::Greater);
+/// ```
+pub fn cmp_end_bound<T: ?Sized + Ord>(a: Bound<&T>, b: Bound<&T>) -> Ordering {
+    match (a, b) {
+        (Unbounded, Unbounded) => Ordering::Equal,
+        (Unbounded, _) => Ordering::Greater,
+        (_, Unbounded) => Ordering::Less,
+        (Included(a), Included(b)) | (Excluded(a), Excluded(b)) => a.cmp(b),
+        (Included(a), Excluded(b)) => a.cmp(b).then(Ordering::Greater),
+        (Excluded(a), Included(b)) => a.cmp(b).then(Ordering::Less),
+    }
+}
+
+/// Orders two ranges by their start bounds, then by their end bounds.
+///
+/// Starts are compared with [`cmp_start_bound`], so `Unbounded` starts
+/// come first and an `Included` start comes before an `Excluded` one on the
+/// same value. Ranges with equal starts are ordered by [`cmp_end_bound`],
+/// making this a total order suitable for [`slice::sort_by`].
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::cmp_by_start;
+/// use std::ops::Bound::{self, *};
+///
+/// let mut ranges: Vec<(Bound<i32>, Bound<i32>)> = vec![
+///     (Excluded(5), Unbounded),
+///     (Included(5), Included(9)),
+///     (Included(5), Excluded(7)),
+/// ];
+/// ranges.sort_by(cmp_by_start);
+/// assert_eq!(
+///     ranges,
+///     [
+///         (Included(5), Excluded(7)),
+///         (Included(5), Included(9)),
+///         (Excluded(5), Unbounded),
+///     ]
+/// );
+/// ```
+pub fn cmp_by_start<T, A, B>(a: &A, b: &B) -> Ordering
+where
+    T: ?Sized + Ord,
+    A: ?Sized + RangeBounds<T>,
+    B: ?Sized + RangeBounds<T>,
+{
+    cmp_start_bound(a.start_bound(), b.start_bound())
+        .then_with(|| cmp_end_bound(a.end_bound(), b.end_bound()))
+}
+
+/// Orders two ranges by their end bounds, then by their start bounds.
+///
+/// Ends are compared with [`cmp_end_bound`], so `Unbounded` ends come last
+/// and an `Excluded` end comes before an `Included` one on the same value.
+/// Ranges with equal ends are ordered by [`cmp_start_bound`].
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::cmp_by_end;
+///
+/// let mut ranges = vec![0..9, 3..7, 1..7];
+/// ranges.sort_by(cmp_by_end);
+/// assert_eq!(ranges, [1..7, 3..7, 0..9]);
+/// ```
+pub fn cmp_by_end<T, A, B>(a: &A, b: &B) -> Ordering
+where
+    T: ?Sized + Ord,
+    A: ?Sized + RangeBounds<T>,
+    B: ?Sized + RangeBounds<T>,
+{
+    cmp_end_bound(a.end_bound(), b.end_bound())
+        .then_with(|| cmp_start_bound(a.start_bound(), b.start_bound()))
+}
+
+impl<T: ?Sized> RangeBounds<T> for RangeFull {
+    fn start_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+}
+
+impl<T> RangeBounds<T> for RangeFrom<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Included(&self.start)
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+}
+
+impl<T> RangeBounds<T> for RangeTo<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Excluded(&self.end)
+    }
+}
+
+impl<T> RangeBounds<T> for Range<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Included(&self.start)
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Excluded(&self.end)
+    }
+}
+
+/// Bounds are read through [`core::ops::RangeBounds`], so an inclusive
+/// range that has been iterated describes the values it has left to yield,
+/// as [`RangeInclusive::contains`] does: a fully consumed `0..=5` ends at
+/// `Excluded(5)` and is [empty](RangeBounds::is_empty).
+///
+/// An iterated range is therefore not judged by its original endpoints.
+/// It cannot be: iteration moves the start forward in place, so the
+/// original start is gone, and std exposes the exhausted flag only through
+/// these bounds.
+impl<T> RangeBounds<T> for RangeInclusive<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        core::ops::RangeBounds::start_bound(self)
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        core::ops::RangeBounds::end_bound(self)
+    }
+}
+
+impl<T> RangeBounds<T> for RangeToInclusive<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Included(&self.end)
+    }
+}
+
+impl<T> RangeBounds<T> for (Bound<T>, Bound<T>) {
+    fn start_bound(&self) -> Bound<&T> {
+        match *self {
+            (Included(ref start), _) => Included(start),
+            (Excluded(ref start), _) => Excluded(start),
+            (Unbounded, _) => Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        match *self {
+            (_, Included(ref end)) => Included(end),
+            (_, Excluded(ref end)) => Excluded(end),
+            (_, Unbounded) => Unbounded,
+        }
+    }
+}
+
+/// The start and end may borrow from different places, with unrelated
+/// lifetimes.
+impl<'a, 'b, T: ?Sized + 'a + 'b> RangeBounds<T> for (Bound<&'a T>, Bound<&'b T>) {
+    fn start_bound(&self) -> Bound<&T> {
+        self.0
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.1
+    }
+}
+
+/// Treats `[start, end]` as the inclusive range `start..=end`.
+///
+/// Arrays coerce to slices, whose inherent `contains` and `is_empty`
+/// methods take precedence over this trait's, so call those as
+/// `RangeBounds::contains(&[3, 7], &5)`.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::RangeBounds;
+///
+/// assert!([3, 7].overlaps(&(5..9)));
+/// assert!([3, 7].overlaps(&(7..9)));
+/// assert!(RangeBounds::contains(&[3, 7], &5));
+/// ```
+impl<T> RangeBounds<T> for [T; 2] {
+    fn start_bound(&self) -> Bound<&T> {
+        Included(&self[0])
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Included(&self[1])
+    }
+}
+
+impl<T> RangeBounds<T> for RangeFrom<&T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Included(self.start)
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+}
+
+impl<T> RangeBounds<T> for RangeTo<&T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Excluded(self.end)
+    }
+}
+
+impl<T> RangeBounds<T> for Range<&T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Included(self.start)
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Excluded(self.end)
+    }
+}
+
+impl<T> RangeBounds<T> for RangeInclusive<&T> {
+    fn start_bound(&self) -> Bound<&T> {
+        core::ops::RangeBounds::start_bound(self)
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        core::ops::RangeBounds::end_bound(self)
+    }
+}
+
+impl<T> RangeBounds<T> for RangeToInclusive<&T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Included(self.end)
+    }
+}
+
+impl<T: ?Sized, R: ?Sized + RangeBounds<T>> RangeBounds<T> for &R {
+    fn start_bound(&self) -> Bound<&T> {
+        (**self).start_bound()
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        (**self).end_bound()
+    }
+}
+
+/// Discarding the result of a query is rejected under
+/// `#![deny(unused_must_use)]`:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use rangebounds_overlaps::RangeBounds;
+///
+/// (0..3).overlaps(&(1..2));
+/// ```
+///
+/// while using it compiles:
+///
+/// ```
+/// #![deny(unused_must_use)]
+/// use rangebounds_overlaps::RangeBounds;
+///
+/// let _ = (0..3).overlaps(&(1..2));
+/// ```
+#[cfg(doctest)]
+pub struct MustUseQueries;
+
+/// The tolerance methods take [`Float`] ranges only, so integer ranges,
+/// whose gaps could overflow, are rejected:
+///
+/// ```compile_fail
+/// use rangebounds_overlaps::RangeBounds;
+///
+/// let _ = (i32::MIN..=i32::MIN).overlaps_within(&(i32::MAX..=i32::MAX), 1);
+/// ```
+///
+/// ```compile_fail
+/// use rangebounds_overlaps::RangeBounds;
+///
+/// let _ = (i32::MAX..=i32::MAX).contains_approx(&i32::MIN, 0);
+/// ```
+#[cfg(doctest)]
+pub struct FloatTolerances;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    fn check<A, B, K>(a: A, b: B, expected: bool)
+    where
+        A: RangeBounds<K>,
+        B: RangeBounds<K>,
+        K: PartialEq<K> + ?Sized + PartialOrd<K>,
+    {
+        assert_eq!(expected, a.overlaps(&b));
+        assert_eq!(expected, b.overlaps(&a));
+    }
+
+    fn bound(kind: usize, value: f64) -> Bound<f64> {
+        match kind {
+            0 => Bound::Included(value),
+            1 => Bound::Excluded(value),
+            _ => Bound::Unbounded,
+        }
+    }
+
+    /// Brute-force answer for `overlaps`: ranges with integer endpoints in `0..=4`
+    /// share an element exactly when they share a multiple of `0.5` in `-2..=6`.
+    fn oracle(a: &(Bound<f64>, Bound<f64>), b: &(Bound<f64>, Bound<f64>)) -> bool {
+        (-4..=12)
+            .map(|i| f64::from(i) / 2.0)
+            .any(|x| a.contains(&x) && b.contains(&x))
+    }
+
+    #[test]
+    fn overlaps_at_excluded_endpoints() {
+        check(
+            (Bound::Excluded(0), Bound::Excluded(3)),
+            (Bound::Excluded(1), Bound::Excluded(3)),
+            true,
+        );
+        check(
+            (Bound::Excluded(0), Bound::Excluded(3)),
+            (Bound::Excluded(3), Bound::Excluded(4)),
+            false,
+        );
+
+        check(
+            (Bound::Excluded(0), Bound::Included(3)),
+            (Bound::Excluded(3), Bound::Excluded(5)),
+            false,
+        );
+    }
+
+    #[test]
+    fn overlaps_meeting_at_excluded_point() {
+        use Bound::*;
+
+        for outer_start in [Included(0), Excluded(0), Unbounded] {
+            for outer_end in [Included(6), Excluded(6), Unbounded] {
+                for (left_end, right_start) in [
+                    (Excluded(3), Excluded(3)),
+                    (Excluded(3), Included(3)),
+                    (Included(3), Excluded(3)),
+                ] {
+                    let left = (outer_start, left_end);
+                    let right = (right_start, outer_end);
+                    assert!(!left.overlaps(&right), "{left:?} and {right:?}");
+                    assert!(!right.overlaps(&left), "{right:?} and {left:?}");
+                }
+                let left = (outer_start, Included(3));
+                let right = (Included(3), outer_end);
+                assert!(left.overlaps(&right), "{left:?} and {right:?}");
+            }
+        }
+        check(..3, (Excluded(3), Unbounded), false);
+        check(0..3, 3..5, false);
+        check(
+            (Excluded(3), Excluded(3)),
+            (Excluded(3), Excluded(3)),
+            false,
+        );
+    }
+
+    #[test]
+    fn single_point_overlaps_agree_with_contains() {
+        assert!((5..=5).overlaps(&(0..10)));
+        assert!(!(10..=10).overlaps(&(0..10)));
+        for range in small_ranges() {
+            for point in -1..=4 {
+                assert_eq!(
+                    (point..=point).overlaps(&range),
+                    range.contains(&point),
+                    "{point} and {range:?}"
+                );
+            }
+        }
+    }
+
+    /// An `i32` that counts how often it is compared.
+    #[derive(Debug)]
+    struct Counted<'a>(i32, &'a core::cell::Cell<usize>);
+
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.partial_cmp(other) == Some(Ordering::Equal)
+        }
+    }
+
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.1.set(self.1.get() + 1);
+            self.0.partial_cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn overlaps_compares_each_pair_of_bounds_once() {
+        let count = core::cell::Cell::new(0);
+        let counted = |start, end| {
+            (
+                Bound::Included(Counted(start, &count)),
+                Bound::Excluded(Counted(end, &count)),
+            )
+        };
+        let (a, b, c) = (counted(0, 5), counted(3, 8), counted(6, 9));
+
+        // The two cross comparisons, stopping after the first that fails.
+        // Only ranges that pass both are then checked for emptiness, with
+        // one comparison each; NaN needs no separate check between values.
+        assert!(a.overlaps(&b));
+        assert_eq!(count.replace(0), 4);
+        assert!(!c.overlaps(&a));
+        assert_eq!(count.replace(0), 1);
+        assert!(!a.overlaps(&c));
+        assert_eq!(count.replace(0), 2);
+
+        // Facing `Unbounded`, a value is compared with itself instead.
+        let from = (Bound::Included(Counted(4, &count)), Bound::Unbounded);
+        assert!(a.overlaps(&from));
+        assert_eq!(count.replace(0), 3);
+        assert!(RangeBounds::<Counted>::overlaps(&from, &..));
+        assert_eq!(count.replace(0), 1);
+    }
+
+    #[test]
+    fn overlaps_mixed_bounds() {
+        check(0..3, (Bound::Excluded(2), Bound::Included(5)), true);
+    }
+
+    #[test]
+    fn overlaps_matches_oracle() {
+        // Every start/end bound kind of both ranges, over all ranges with
+        // endpoints in `0..=4`, including empty and backwards ones.
+        let endpoints: Vec<(f64, f64)> = (0..=4)
+            .flat_map(|start| (0..=4).map(move |end| (f64::from(start), f64::from(end))))
+            .collect();
+        for kind in 0..81 {
+            for &(a_start, a_end) in &endpoints {
+                for &(b_start, b_end) in &endpoints {
+                    let a = (bound(kind / 27, a_start), bound(kind / 9 % 3, a_end));
+                    let b = (bound(kind / 3 % 3, b_start), bound(kind % 3, b_end));
+                    let expected = oracle(&a, &b);
+                    check(a, b, expected);
+                }
+            }
+        }
+    }
+
+    /// Every range with endpoints in `0..=3` and any kind of start and end
+    /// bound, including empty and backwards ones.
+    fn small_ranges() -> Vec<(Bound<i32>, Bound<i32>)> {
+        let bounds = |value| {
+            [
+                Bound::Included(value),
+                Bound::Excluded(value),
+                Bound::Unbounded,
+            ]
+        };
+        (0..=3)
+            .flat_map(|start| (0..=3).map(move |end| (start, end)))
+            .flat_map(|(start, end)| {
+                bounds(start)
+                    .into_iter()
+                    .flat_map(move |start| bounds(end).into_iter().map(move |end| (start, end)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn symmetric_operations() {
+        let ranges = small_ranges();
+        for a in &ranges {
+            for b in &ranges {
+                assert_eq!(a.overlaps(b), b.overlaps(a), "{a:?} and {b:?}");
+                assert_eq!(a.touches(b), b.touches(a), "{a:?} and {b:?}");
+                assert_eq!(
+                    a.relation(b),
+                    b.relation(a).map(IntervalRelation::inverse),
+                    "{a:?} and {b:?}"
+                );
+                assert_eq!(a.gap(b), b.gap(a), "{a:?} and {b:?}");
+                assert_eq!(
+                    a.symmetric_difference(b),
+                    b.symmetric_difference(a),
+                    "{a:?} and {b:?}"
+                );
+                assert_eq!(a.union(b), b.union(a), "{a:?} and {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn borrowed_tuples_agree_with_owned() {
+        // The two operands borrow from separate vectors, with lifetimes that
+        // are not tied to each other.
+        let owned = small_ranges();
+        let others = small_ranges();
+        for a in &owned {
+            let borrowed_a = (a.0.as_ref(), a.1.as_ref());
+            for b in &others {
+                let borrowed_b = (b.0.as_ref(), b.1.as_ref());
+                let expected = a.overlaps(b);
+                assert_eq!(
+                    RangeBounds::<i32>::overlaps(&borrowed_a, &borrowed_b),
+                    expected,
+                    "{a:?} and {b:?}"
+                );
+                assert_eq!(
+                    RangeBounds::<i32>::overlaps(&borrowed_a, b),
+                    expected,
+                    "{a:?} and {b:?}"
+                );
+                assert_eq!(a.overlaps(&borrowed_b), expected, "{a:?} and {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn borrowed_tuples_with_independent_lifetimes() {
+        // Only compiles if the impl accepts a start and an end with
+        // different lifetimes: the end is handed back with its own, longer
+        // lifetime after the start's value is gone.
+        fn end_if_overlapping<'s, 'l>(
+            range: (Bound<&'s i32>, Bound<&'l i32>),
+            other: &Range<i32>,
+        ) -> Option<&'l i32>
+        where
+            (Bound<&'s i32>, Bound<&'l i32>): RangeBounds<i32>,
+        {
+            match range.1 {
+                Bound::Included(end) | Bound::Excluded(end) if range.overlaps(other) => Some(end),
+                _ => None,
+            }
+        }
+
+        let end = 10;
+        let found = {
+            let start = 2;
+            let range = (Bound::Included(&start), Bound::Excluded(&end));
+            assert!(RangeBounds::<i32>::contains(&range, &5));
+            end_if_overlapping(range, &(8..12))
+        };
+        assert_eq!(found, Some(&10));
+    }
+
+    #[test]
+    fn borrowed_tuples_against_std_ranges() {
+        let (start, end) = (2, 5);
+        let borrowed: (Bound<&i32>, Bound<&i32>) = (Bound::Excluded(&start), Bound::Included(&end));
+        assert!(RangeBounds::<i32>::overlaps(&borrowed, &(0..3)));
+        assert!(!RangeBounds::<i32>::overlaps(&borrowed, &(0..=2)));
+        assert!(RangeBounds::<i32>::overlaps(&borrowed, &(5..)));
+        assert!(!RangeBounds::<i32>::overlaps(&borrowed, &(6..)));
+        assert!(RangeBounds::<i32>::overlaps(&borrowed, &..));
+        assert!((..=5).overlaps(&borrowed));
+        assert!(!(..=2).overlaps(&borrowed));
+
+        let key = std::string::String::from("m");
+        let borrowed: (Bound<&str>, Bound<&str>) = (Bound::Included(&key), Bound::Unbounded);
+        assert!(RangeBounds::<&str>::overlaps(&borrowed, &("a"..="m")));
+        assert!(!RangeBounds::<&str>::overlaps(&borrowed, &("a".."m")));
+    }
+
+    #[test]
+    fn as_std_range_shapes() {
+        use Bound::*;
+
+        let range: (Bound<i32>, Bound<i32>) = (Included(3), Excluded(5));
+        assert_eq!(range.as_range(), Some(3..5));
+        assert_eq!(range.as_range_inclusive(), None);
+        assert_eq!((Excluded(3), Excluded(5)).as_range(), None);
+        assert_eq!((Included(3), Included(5)).as_range(), None);
+
+        assert_eq!((Included(3), Included(5)).as_range_inclusive(), Some(3..=5));
+        assert_eq!((Included(3), Unbounded).as_range_from(), Some(3..));
+        assert_eq!((Unbounded, Excluded(5)).as_range_to(), Some(..5));
+        assert_eq!((Unbounded, Included(5)).as_range_to_inclusive(), Some(..=5));
+        assert_eq!((Excluded(3), Unbounded).as_range_from(), None);
+        assert_eq!(RangeBounds::<i32>::as_range_to(&..), None);
+    }
+
+    #[test]
+    fn as_std_range_round_trips() {
+        fn bounds<R: RangeBounds<i32>>(range: R) -> (Bound<i32>, Bound<i32>) {
+            (range.start_bound().cloned(), range.end_bound().cloned())
+        }
+        assert_eq!(bounds(3..5).as_range(), Some(3..5));
+        assert_eq!(bounds(3..=5).as_range_inclusive(), Some(3..=5));
+        assert_eq!(bounds(3..).as_range_from(), Some(3..));
+        assert_eq!(bounds(..5).as_range_to(), Some(..5));
+        assert_eq!(bounds(..=5).as_range_to_inclusive(), Some(..=5));
+        assert_eq!(bounds(3..5).as_range_inclusive(), None);
+    }
+
+    #[test]
+    fn set_operations_never_return_empty_ranges() {
+        use Bound::*;
+
+        assert_eq!((0..3).intersection(&(3..6)), None);
+        assert_eq!((0..=3).intersection(&(Excluded(3), Included(6))), None);
+        assert_eq!((5..5).intersection(&(0..10)), None);
+
+        let ranges = small_ranges();
+        for a in &ranges {
+            for b in &ranges {
+                let (below, above) = a.difference(b);
+                let (lower, higher) = a.symmetric_difference(b);
+                let results = [
+                    a.intersection(b),
+                    a.clamp_range(b),
+                    a.saturating_within(b),
+                    a.union(b),
+                    a.gap(b),
+                    below,
+                    above,
+                    lower,
+                    higher,
+                ];
+                for result in results.into_iter().flatten() {
+                    assert!(!result.is_empty(), "{a:?} and {b:?} gave {result:?}");
+                }
+            }
+        }
+    }
+
+    /// A xorshift generator, so random cases are reproducible without a
+    /// property-testing dependency.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// Returns a bound on an even value in `-20..=20`, so that distinct
+        /// endpoints always have an odd integer between them.
+        fn bound(&mut self) -> Bound<i32> {
+            let value = (self.next() % 21) as i32 * 2 - 20;
+            match self.next() % 3 {
+                0 => Bound::Included(value),
+                1 => Bound::Excluded(value),
+                _ => Bound::Unbounded,
+            }
+        }
+    }
+
+    #[test]
+    fn overlaps_matches_brute_force_on_random_ranges() {
+        let mut rng = Rng(0x5eed_1e55_ba5e_ba11);
+        for _ in 0..10_000 {
+            let a = (rng.bound(), rng.bound());
+            let b = (rng.bound(), rng.bound());
+            let expected =
+                (-25..=25).any(|x| RangeBounds::contains(&a, &x) && RangeBounds::contains(&b, &x));
+            assert_eq!(a.overlaps(&b), expected, "{a:?} and {b:?}");
+        }
+    }
+
+    #[test]
+    fn exhausted_range_inclusive() {
+        let mut range = 0..=5;
+        range.next();
+        assert_eq!(range.start_bound(), Bound::Included(&1));
+        assert_eq!(range.end_bound(), Bound::Included(&5));
+        assert!(!range.overlaps(&(..1)));
+        assert!(range.overlaps(&(5..)));
+
+        range.by_ref().for_each(drop);
+        assert_eq!(range.start_bound(), Bound::Included(&5));
+        assert_eq!(range.end_bound(), Bound::Excluded(&5));
+        assert!(RangeBounds::is_empty(&range));
+        assert!(!range.overlaps(&(0..10)));
+        for value in 0..=6 {
+            assert_eq!(
+                RangeBounds::contains(&range, &value),
+                range.contains(&value)
+            );
+        }
+
+        let mut range = 5..=5;
+        assert!(range.overlaps(&(5..6)));
+        range.next();
+        assert!(!range.overlaps(&(5..6)));
+        assert!(!RangeBounds::contains(&range, &5));
+
+        let range = &0..=&5;
+        assert_eq!(range.end_bound(), Bound::Included(&5));
+        assert!(range.overlaps(&(5..)));
+    }
+
+    #[test]
+    fn arrays() {
+        assert_eq!([3, 7].start_bound(), Bound::Included(&3));
+        assert_eq!([3, 7].end_bound(), Bound::Included(&7));
+        assert!([3, 7].overlaps(&(5..9)));
+        assert!([3, 7].overlaps(&(7..9)));
+        assert!(!([3, 7].overlaps(&(Bound::Excluded(7), Bound::Unbounded))));
+        assert!([3, 7].overlaps(&[0, 3]));
+        assert!(!([3, 7].overlaps(&(0..3))));
+        assert!(RangeBounds::contains(&[3, 7], &7));
+        assert!(!RangeBounds::contains(&[3, 7], &8));
+        assert!(!RangeBounds::is_empty(&[5, 5]));
+        assert!(RangeBounds::is_empty(&[7, 3]));
+        assert_eq!([0.5, 2.0].measure(), Some(1.5));
+        for range in small_ranges() {
+            for start in 0..=3 {
+                for end in 0..=3 {
+                    assert_eq!(
+                        [start, end].overlaps(&range),
+                        (start..=end).overlaps(&range)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn overlaps_through_references() {
+        let range = 3..5;
+        check(&range, 0..4, true);
+        check(&range, &(5..7), false);
+        check(&range, (Bound::Excluded(2), Bound::Included(3)), true);
+        assert!((0..4).overlaps(&&range));
+        assert!(RangeBounds::contains(&&range, &4));
+    }
+
+    #[test]
+    fn overlaps_empty_ranges() {
+        check(5..5, 0..10, false);
+        check(0..0, 0..0, false);
+        check(
+            (Bound::Excluded(5), Bound::Excluded(5)),
+            (Bound::Included(0), Bound::Included(10)),
+            false,
+        );
+        check((Bound::Excluded(5), Bound::Included(5)), .., false);
+        check(
+            (Bound::Included(7), Bound::Included(3)),
+            (Bound::Included(0), Bound::Included(10)),
+            false,
+        );
+        check(5..=5, 0..10, true);
+        check(5..=5, 5..=5, true);
+        check(
+            (Bound::Included(5), Bound::Included(5)),
+            (Bound::Excluded(5), Bound::Unbounded),
+            false,
+        );
+    }
+
+    #[test]
+    fn range_full() {
+        let full = RangeFull;
+        assert_eq!(RangeBounds::<i32>::start_bound(&full), Bound::Unbounded);
+        assert_eq!(RangeBounds::<i32>::end_bound(&full), Bound::Unbounded);
+        assert!((..).overlaps(&(3..5)));
+        assert!((..).overlaps(&(3..=3)));
+        assert!((..).overlaps(&(..-5)));
+        assert!((..).overlaps(&(f64::MAX..)));
+        assert!(RangeBounds::<i32>::overlaps(&.., &..));
+        assert!(RangeBounds::<i32>::overlaps(&&full, &&full));
+        for value in [i32::MIN, -1, 0, 1, i32::MAX] {
+            assert!(RangeBounds::contains(&.., &value));
+        }
+        assert!(RangeBounds::contains(&.., &f64::INFINITY));
+        assert!(RangeBounds::<str>::contains(&.., "anything"));
+        assert!(!(..).overlaps(&(3..3)));
+        assert!(!(..).overlaps(&(Bound::Excluded(3), Bound::Included(3))));
+        assert!(!RangeBounds::<i32>::overlaps(
+            &&full,
+            &(Bound::Included(5), Bound::Excluded(3))
+        ));
+    }
+
+    #[test]
+    fn unbounded_ranges_skip_empty_ranges() {
+        use Bound::*;
+
+        check(.., 5..5, false);
+        check(.., (Excluded(5), Excluded(5)), false);
+        check(.., (Excluded(5), Included(5)), false);
+        check(.., (Included(7), Included(3)), false);
+        check(..=10, 5..5, false);
+        check(0.., (Excluded(5), Included(5)), false);
+        let full: (Bound<i32>, Bound<i32>) = (Unbounded, Unbounded);
+        check(full, (Included(7), Excluded(3)), false);
+        check(full, full, true);
+        check(.., 5..=5, true);
+        check(.., ..5, true);
+        assert!(!RangeBounds::<i32>::is_empty(&..));
+        assert!(!RangeBounds::<i32>::overlaps(&.., &(5..5)));
+    }
+
+    /// `Instant` and `Duration` ranges go through the generic impls like any
+    /// other ordered type.
+    mod time {
+        use super::*;
+        use std::time::{Duration, Instant};
+
+        #[test]
+        fn instant_ranges() {
+            let t0 = Instant::now();
+            let [t1, t2, t3] = [1, 2, 3].map(|secs| t0 + Duration::from_secs(secs));
+
+            assert!((t0..t2).overlaps(&(t1..t3)));
+            assert!(!(t0..t1).overlaps(&(t1..t3)));
+            assert!((t0..=t1).overlaps(&(t1..t3)));
+            assert!((t0..t2).contains(&t1));
+            assert!(!(t0..t2).contains(&t2));
+            assert!((t1..).contains(&t3));
+            assert_eq!(
+                (t0..t2).intersection(&(t1..t3)),
+                Some((Bound::Included(t1), Bound::Excluded(t2)))
+            );
+            assert_eq!((t0..t1).intersection(&(t2..t3)), None);
+        }
+
+        #[test]
+        fn instants_from_the_monotonic_clock() {
+            // Successive readings never go backwards, but may be equal, so
+            // `earlier..later` can be empty while `earlier..=later` cannot.
+            let earlier = Instant::now();
+            let later = Instant::now();
+            assert!(!(earlier..=later).is_empty());
+            assert!((earlier..=later).overlaps(&(later..=later)));
+            assert_eq!((earlier..later).is_empty(), earlier == later);
+            assert!(!(earlier..earlier).overlaps(&(..)));
+            assert!((..earlier).touches(&(earlier..)));
+        }
+
+        #[test]
+        fn duration_ranges() {
+            let ms = Duration::from_millis;
+            assert!((ms(0)..ms(100)).overlaps(&(ms(99)..ms(200))));
+            assert!(!(ms(0)..ms(100)).overlaps(&(ms(100)..ms(200))));
+            assert!((Duration::ZERO..).contains(&Duration::MAX));
+            assert!((..ms(1)).contains(&Duration::ZERO));
+            assert_eq!(
+                (ms(0)..ms(100)).intersection(&(ms(50)..=ms(150))),
+                Some((Bound::Included(ms(50)), Bound::Excluded(ms(100))))
+            );
+            assert_eq!(
+                (ms(0)..ms(100)).offset(ms(5)),
+                (Bound::Included(ms(5)), Bound::Excluded(ms(105)))
+            );
+            assert_eq!((ms(0)..Duration::MAX).checked_offset(ms(1)), None);
+        }
+
+        #[test]
+        fn duration_offsets_saturate() {
+            use Bound::*;
+
+            let secs = Duration::from_secs;
+            // The start stops at zero while the end still moves.
+            assert_eq!(
+                (secs(1)..secs(15)).saturating_offset_sub(secs(10)),
+                (Included(Duration::ZERO), Excluded(secs(5)))
+            );
+            // Both ends stop at zero, leaving nothing.
+            let gone = (secs(1)..secs(5)).saturating_offset_sub(secs(10));
+            assert_eq!(gone, (Included(Duration::ZERO), Excluded(Duration::ZERO)));
+            assert!(gone.is_empty());
+            assert_eq!(
+                (secs(1)..=secs(5)).saturating_offset_sub(secs(10)),
+                (Included(Duration::ZERO), Included(Duration::ZERO))
+            );
+            assert_eq!(
+                (Excluded(secs(3)), Unbounded).saturating_offset_sub(secs(1)),
+                (Excluded(secs(2)), Unbounded)
+            );
+
+            // Upwards the ends stop at `Duration::MAX`.
+            assert_eq!(
+                (secs(1)..Duration::MAX).saturating_offset(secs(1)),
+                (Included(secs(2)), Excluded(Duration::MAX))
+            );
+            assert_eq!(
+                (Duration::MAX - secs(1)..=Duration::MAX).saturating_offset(secs(5)),
+                (Included(Duration::MAX), Included(Duration::MAX))
+            );
+            assert_eq!(
+                (..secs(5)).saturating_offset(secs(1)),
+                (Unbounded, Excluded(secs(6)))
+            );
+        }
+    }
+
+    mod nan {
+        use super::*;
+
+        #[test]
+        fn try_overlaps() {
+            for a in nan_ranges() {
+                assert_eq!(a.try_overlaps(&(0.0..1.0)), Err(Incomparable));
+                assert_eq!((0.0..1.0).try_overlaps(&a), Err(Incomparable));
+                assert_eq!(a.try_overlaps(&(..)), Err(Incomparable));
+            }
+            assert_eq!((0.0..1.0).try_overlaps(&(f32::NAN..2.0)), Err(Incomparable));
+            assert_eq!((5.0..3.0).try_overlaps(&(f32::NAN..2.0)), Err(Incomparable));
+        }
+
+        fn nan_ranges() -> Vec<(Bound<f64>, Bound<f64>)> {
+            let mut ranges = Vec::new();
+            for nan in [0, 1].map(|kind| bound(kind, f64::NAN)) {
+                for other in [0, 1, 2].map(|kind| bound(kind, 1.0)) {
+                    ranges.push((nan, other));
+                    ranges.push((other, nan));
+                }
+                ranges.push((nan, nan));
+            }
+            ranges
+        }
+
+        #[test]
+        fn is_empty() {
+            for range in nan_ranges() {
+                assert!(range.is_empty(), "{range:?}");
+                assert!(!range.contains(&0.5), "{range:?}");
+            }
+        }
+
+        #[test]
+        fn overlaps_nothing() {
+            let finite = [
+                (Bound::Included(0.0), Bound::Excluded(1.0)),
+                (Bound::Included(-1.0), Bound::Included(2.0)),
+                (Bound::Excluded(0.5), Bound::Unbounded),
+                (Bound::Unbounded, Bound::Excluded(0.5)),
+                (Bound::Unbounded, Bound::Unbounded),
+            ];
+            for a in nan_ranges() {
+                for b in finite.iter().chain(&nan_ranges()) {
+                    assert!(!a.overlaps(b), "{a:?} overlaps {b:?}");
+                    assert!(!b.overlaps(&a), "{b:?} overlaps {a:?}");
+                    assert!(!a.touches(b), "{a:?} touches {b:?}");
+                    assert_eq!(a.relation(b), None, "{a:?} relation {b:?}");
+                }
+            }
+        }
+
+        #[test]
+        fn overlaps_std_ranges() {
+            assert!(!(0.0..1.0).overlaps(&(f32::NAN..2.0)));
+            assert!(!(f32::NAN..2.0).overlaps(&(0.0..1.0)));
+            assert!(!(0.0..1.0).overlaps(&(0.5..f32::NAN)));
+            assert!(!(0.0..=1.0).overlaps(&(f32::NAN..)));
+            assert!(!(..).overlaps(&(f32::NAN..)));
+            assert!(!(..).overlaps(&(..f32::NAN)));
+            assert!((0.0..1.0).overlaps(&(0.5..2.0)));
+        }
+    }
+
+    /// A bound value whose comparison with [`Probe`] is honest.
+    #[derive(Debug, PartialEq)]
+    struct Mark(i32);
+
+    /// An item that claims to be less than every [`Mark`], disagreeing with
+    /// `Mark`'s own comparison whenever it is not.
+    #[derive(Debug, PartialEq)]
+    struct Probe(i32);
+
+    impl PartialEq<Probe> for Mark {
+        fn eq(&self, other: &Probe) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl PartialOrd<Probe> for Mark {
+        fn partial_cmp(&self, other: &Probe) -> Option<Ordering> {
+            self.0.partial_cmp(&other.0)
+        }
+    }
+
+    impl PartialEq<Mark> for Probe {
+        fn eq(&self, _: &Mark) -> bool {
+            false
+        }
+    }
+
+    impl PartialOrd<Mark> for Probe {
+        fn partial_cmp(&self, _: &Mark) -> Option<Ordering> {
+            Some(Ordering::Less)
+        }
+    }
+
+    #[test]
+    fn contains_with_asymmetric_partial_ord() {
+        use Bound::*;
+
+        // Starts are compared through `Mark`'s impl and ends through
+        // `Probe`'s, which always puts the item before the end.
+        let range = (Included(Mark(0)), Excluded(Mark(5)));
+        assert!(RangeBounds::contains(&range, &Probe(3)));
+        assert!(RangeBounds::contains(&range, &Probe(10)));
+        assert!(!RangeBounds::contains(&range, &Probe(-1)));
+        assert!(RangeBounds::contains(
+            &(Excluded(Mark(0)), Unbounded),
+            &Probe(1)
+        ));
+        assert!(!RangeBounds::contains(
+            &(Excluded(Mark(0)), Unbounded),
+            &Probe(0)
+        ));
+        assert!(RangeBounds::contains(
+            &(Unbounded, Included(Mark(0))),
+            &Probe(7)
+        ));
+
+        // `core::ops::RangeBounds::contains` makes the same comparisons.
+        for value in -2..=12 {
+            for range in [
+                (Included(Mark(0)), Excluded(Mark(5))),
+                (Excluded(Mark(0)), Included(Mark(5))),
+                (Unbounded, Excluded(Mark(5))),
+            ] {
+                assert_eq!(
+                    RangeBounds::contains(&range, &Probe(value)),
+                    core::ops::RangeBounds::contains(&range, &Probe(value)),
+                    "{range:?} {value}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn contains_borrowed() {
+        use std::string::{String, ToString};
+        use std::vec;
+
+        let range = "b".to_string().."n".to_string();
+        assert!(range.contains_borrowed("b"));
+        assert!(range.contains_borrowed("m"));
+        assert!(!range.contains_borrowed("n"));
+        assert!(!range.contains_borrowed("a"));
+        assert!(("b".to_string()..).contains_borrowed("zzz"));
+        assert!(!(.."b".to_string()).contains_borrowed("b"));
+        assert!(("b".to_string()..="n".to_string()).contains_borrowed("n"));
+        assert!((Bound::Excluded("b".to_string()), Bound::Unbounded).contains_borrowed("ba"));
+        assert!(
+            !(Bound::<String>::Excluded("b".to_string()), Bound::Unbounded).contains_borrowed("b")
+        );
+
+        let range = vec![1, 2]..vec![3];
+        assert!(range.contains_borrowed(&[2, 9][..]));
+        assert!(!range.contains_borrowed(&[3][..]));
+
+        assert!((0..10).contains_borrowed(&5));
+    }
+
+    #[test]
+    fn contains_approx() {
+        use Bound::*;
+
+        let epsilon = 1e-9;
+        // Near an excluded endpoint, on either side, counts as outside.
+        let half_open = (Excluded(0.0), Excluded(1.0));
+        assert!(half_open.contains_approx(&0.5, epsilon));
+        assert!(!half_open.contains_approx(&(1.0 - 1e-12), epsilon));
+        assert!(!half_open.contains_approx(&1e-12, epsilon));
+        assert!(!half_open.contains_approx(&1.0, epsilon));
+        assert!(half_open.contains_approx(&(1.0 - 1e-6), epsilon));
+        // Near an included endpoint, on either side, counts as inside.
+        let closed = 0.0..=0.3;
+        assert!(closed.contains_approx(&(0.1 + 0.2), epsilon));
+        assert!(closed.contains_approx(&-1e-12, epsilon));
+        assert!(!closed.contains_approx(&0.31, epsilon));
+        assert!(!closed.contains_approx(&-1e-6, epsilon));
+        assert!((Unbounded, Included(0.3)).contains_approx(&-1e300, epsilon));
+
+        // Without a tolerance it agrees with `contains`.
+        for range in small_ranges() {
+            let range = range.map(|&x| f64::from(x));
+            for value in (-2..=8).map(|x| f64::from(x) / 2.0) {
+                for epsilon in [0.0, -1.0] {
+                    assert_eq!(
+                        range.contains_approx(&value, epsilon),
+                        range.contains(&value),
+                        "{range:?} {value}"
+                    );
+                }
+            }
+        }
+
+        // Empty ranges and NaNs contain nothing.
+        assert!(!(Included(1.0), Included(0.9)).contains_approx(&0.95, 0.2));
+        assert!(!(Included(1.0), Excluded(1.0)).contains_approx(&1.0, epsilon));
+        assert!(!(0.0..1.0).contains_approx(&f64::NAN, epsilon));
+        assert!(!(0.0..1.0).contains_approx(&0.5, f64::NAN));
+        assert!(!(f64::NAN..1.0).contains_approx(&0.5, epsilon));
+        assert!(!RangeBounds::<f64>::contains_approx(
+            &..,
+            &f64::NAN,
+            epsilon
+        ));
+
+        // Distances too large for the type go to infinity instead of
+        // overflowing.
+        assert!(!(f64::MAX..=f64::MAX).contains_approx(&f64::MIN, 0.0));
+        assert!(!(f64::MIN..=f64::MIN).contains_approx(&f64::MAX, f64::MAX));
+        assert!((Excluded(f32::MIN), Excluded(f32::MAX)).contains_approx(&0.0, f32::MAX));
+        assert!((Excluded(f64::NEG_INFINITY), Unbounded).contains_approx(&f64::MAX, 1.0));
+        assert!((f64::INFINITY..).contains_approx(&f64::INFINITY, 1.0));
+    }
+
+    #[test]
+    fn contains_all() {
+        use Bound::*;
+
+        let range = (Excluded(0), Included(10));
+        assert!(range.contains_all([1, 5, 10]));
+        assert!(range.contains_all(1..=10));
+        assert!(!range.contains_all([1, 0, 5]));
+        assert!(!range.contains_all(0..10));
+        assert!(range.contains_all(core::iter::empty::<i32>()));
+        assert!((5..5).contains_all(core::iter::empty::<i32>()));
+        assert!(!(0.0..1.0).contains_all([0.5, f64::NAN]));
+
+        let items = [1, 20].into_iter().chain(core::iter::from_fn(|| {
+            panic!("visited an item after one outside the range")
+        }));
+        assert!(!range.contains_all(items));
+    }
+
+    #[test]
+    fn clamp_value() {
+        assert_eq!((0..10).clamp_value(5), 5);
+        assert_eq!((0..10).clamp_value(0), 0);
+        assert_eq!((0..10).clamp_value(-5), 0);
+        assert_eq!((0..10).clamp_value(10), 10);
+        assert_eq!((0..10).clamp_value(15), 10);
+        assert_eq!((0..=10).clamp_value(10), 10);
+        assert_eq!((0..=10).clamp_value(15), 10);
+        assert_eq!((0..=10).clamp_value(-5), 0);
+        assert_eq!((Bound::Excluded(0), Bound::Unbounded).clamp_value(-5), 0);
+        assert_eq!((3..).clamp_value(i32::MAX), i32::MAX);
+        assert_eq!((..=3).clamp_value(i32::MIN), i32::MIN);
+        assert_eq!(RangeBounds::<i32>::clamp_value(&.., 7), 7);
+    }
+
+    #[test]
+    fn cmp_start_bound() {
+        use Bound::*;
+
+        assert_eq!(
+            super::cmp_start_bound(Included(&5), Included(&5)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            super::cmp_start_bound(Excluded(&5), Excluded(&5)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            super::cmp_start_bound(Included(&5), Excluded(&5)),
+            Ordering::Less
+        );
+        assert_eq!(
+            super::cmp_start_bound(Excluded(&5), Included(&5)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            super::cmp_start_bound(Excluded(&5), Included(&6)),
+            Ordering::Less
+        );
+        assert_eq!(
+            super::cmp_start_bound(Included(&6), Excluded(&5)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            super::cmp_start_bound::<i32>(Unbounded, Unbounded),
+            Ordering::Equal
+        );
+        assert_eq!(
+            super::cmp_start_bound(Unbounded, Included(&5)),
+            Ordering::Less
+        );
+        assert_eq!(
+            super::cmp_start_bound(Excluded(&5), Unbounded),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn cmp_by_start_and_end() {
+        use Bound::*;
+
+        let mut ranges = [
+            BoundRange(Excluded(3), Included(4)),
+            BoundRange(Included(3), Unbounded),
+            BoundRange(Unbounded, Excluded(1)),
+            BoundRange(Included(3), Excluded(4)),
+            BoundRange(Included(0), Included(4)),
+            BoundRange(Unbounded, Unbounded),
+        ];
+        ranges.sort_by(super::cmp_by_start);
+        assert_eq!(
+            ranges,
+            [
+                BoundRange(Unbounded, Excluded(1)),
+                BoundRange(Unbounded, Unbounded),
+                BoundRange(Included(0), Included(4)),
+                BoundRange(Included(3), Excluded(4)),
+                BoundRange(Included(3), Unbounded),
+                BoundRange(Excluded(3), Included(4)),
+            ]
+        );
+
+        ranges.sort_by(super::cmp_by_end);
+        assert_eq!(
+            ranges,
+            [
+                BoundRange(Unbounded, Excluded(1)),
+                BoundRange(Included(3), Excluded(4)),
+                BoundRange(Included(0), Included(4)),
+                BoundRange(Excluded(3), Included(4)),
+                BoundRange(Unbounded, Unbounded),
+                BoundRange(Included(3), Unbounded),
+            ]
+        );
+
+        assert_eq!(
+            super::cmp_by_start(&(0..3), &(Included(0), Included(2))),
+            Ordering::Greater
+        );
+        assert_eq!(super::cmp_by_start(&(0..3), &[0, 3]), Ordering::Less);
+        assert_eq!(super::cmp_by_end(&(..=3), &(2..=3)), Ordering::Less);
+        assert_eq!(super::cmp_by_start(&(1..3), &(1..3)), Ordering::Equal);
+    }
+
+    #[test]
+    fn cmp_end_bound() {
+        use Bound::*;
+
+        assert_eq!(
+            super::cmp_end_bound(Included(&5), Included(&5)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            super::cmp_end_bound(Excluded(&5), Excluded(&5)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            super::cmp_end_bound(Included(&5), Excluded(&5)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            super::cmp_end_bound(Excluded(&5), Included(&5)),
+            Ordering::Less
+        );
+        assert_eq!(
+            super::cmp_end_bound(Excluded(&6), Included(&5)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            super::cmp_end_bound(Included(&5), Excluded(&6)),
+            Ordering::Less
+        );
+        assert_eq!(
+            super::cmp_end_bound::<i32>(Unbounded, Unbounded),
+            Ordering::Equal
+        );
+        assert_eq!(
+            super::cmp_end_bound(Unbounded, Included(&5)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            super::cmp_end_bound(Excluded(&5), Unbounded),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn measure() {
+        assert_eq!((0..10).measure(), Some(10));
+        assert_eq!((0..=10).measure(), Some(10));
+        assert_eq!(
+            (Bound::Excluded(0), Bound::Excluded(10)).measure(),
+            Some(10)
+        );
+        assert_eq!((-3.0..4.5).measure(), Some(7.5));
+        assert_eq!((5..5).measure(), Some(0));
+        assert_eq!((5u32..=5).measure(), Some(0));
+        assert_eq!(
+            (Bound::Included(7u32), Bound::Excluded(3)).measure(),
+            Some(0)
+        );
+        assert_eq!((3..).measure(), None);
+        assert_eq!((..3).measure(), None);
+        assert_eq!(RangeBounds::<i32>::measure(&..), None);
+
+        // A NaN endpoint makes the range empty, so it measures zero.
+        assert_eq!((f64::NAN..1.0).measure(), Some(0.0));
+        assert_eq!((1.0..=f64::NAN).measure(), Some(0.0));
+        assert_eq!(
+            (Bound::Excluded(f64::NAN), Bound::Excluded(-2.0)).measure(),
+            Some(0.0)
+        );
+        assert_eq!((f64::INFINITY..=1.0).measure(), Some(0.0));
+        assert_eq!((f64::NAN..f64::NAN).measure(), None);
+        assert_eq!((f64::INFINITY..f64::INFINITY).measure(), None);
+    }
+
+    #[test]
+    fn cardinality() {
+        use Bound::*;
+
+        let expected = [
+            ((Included(0), Included(10)), Some(11)),
+            ((Included(0), Excluded(10)), Some(10)),
+            ((Included(0), Unbounded), None),
+            ((Excluded(0), Included(10)), Some(10)),
+            ((Excluded(0), Excluded(10)), Some(9)),
+            ((Excluded(0), Unbounded), None),
+            ((Unbounded, Included(10)), None),
+            ((Unbounded, Excluded(10)), None),
+            ((Unbounded, Unbounded), None),
+        ];
+        for (range, count) in expected {
+            assert_eq!(range.cardinality(), count, "{range:?}");
+        }
+
+        assert_eq!((0..10).cardinality(), Some(10));
+        assert_eq!((0..=10).cardinality(), Some(11));
+        assert_eq!((5..=5).cardinality(), Some(1));
+        assert_eq!((5..5).cardinality(), Some(0));
+        assert_eq!((Excluded(5), Excluded(6)).cardinality(), Some(0));
+        assert_eq!((Excluded(5), Included(5)).cardinality(), Some(0));
+        assert_eq!((Included(7), Included(3)).cardinality(), Some(0));
+        assert_eq!(
+            (Excluded(u8::MAX), Included(u8::MAX)).cardinality(),
+            Some(0)
+        );
+        assert_eq!((Included(0), Excluded(0u8)).cardinality(), Some(0));
+        assert_eq!((u8::MIN..=u8::MAX).cardinality(), Some(256));
+        assert_eq!((i128::MIN..=i128::MAX).cardinality(), None);
+        assert_eq!((i128::MIN..i128::MAX).cardinality(), Some(u128::MAX));
+    }
+
+    #[test]
+    fn min_and_max_value() {
+        use Bound::*;
+
+        assert_eq!((Excluded(0), Included(5)).min_value(), Some(1));
+        assert_eq!((Excluded(0), Included(5)).max_value(), Some(5));
+        assert_eq!((Included(0), Excluded(5)).min_value(), Some(0));
+        assert_eq!((Included(0), Excluded(5)).max_value(), Some(4));
+        assert_eq!((Excluded(0), Excluded(2)).min_value(), Some(1));
+        assert_eq!((Excluded(0), Excluded(2)).max_value(), Some(1));
+        assert_eq!((Excluded(0), Excluded(1)).min_value(), None);
+        assert_eq!((Excluded(0), Excluded(1)).max_value(), None);
+        assert_eq!((Excluded(3), Included(3)).min_value(), None);
+        assert_eq!((Included(3), Excluded(3)).max_value(), None);
+        assert_eq!((Included(5), Included(3)).min_value(), None);
+
+        assert_eq!((Excluded(u8::MAX), Unbounded).min_value(), None);
+        assert_eq!((Unbounded, Excluded(i8::MIN)).max_value(), None);
+        assert_eq!((Excluded(254u8), Unbounded).min_value(), Some(255));
+        assert_eq!((3..).min_value(), Some(3));
+        assert_eq!((3..).max_value(), None);
+        assert_eq!((..3).min_value(), None);
+        assert_eq!((..3).max_value(), Some(2));
+
+        for range in small_ranges() {
+            if range.start_bound() != Unbounded && range.end_bound() != Unbounded {
+                let values: Vec<_> = range.iter().collect();
+                assert_eq!(range.min_value(), values.first().copied(), "{range:?}");
+                assert_eq!(range.max_value(), values.last().copied(), "{range:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn just_below_and_above() {
+        use Bound::*;
+
+        assert_eq!((3..10).just_below(), Some(2));
+        assert_eq!((3..10).just_above(), Some(10));
+        assert_eq!((Excluded(2), Included(9)).just_below(), Some(2));
+        assert_eq!((Excluded(2), Included(9)).just_above(), Some(10));
+        assert_eq!((..10).just_below(), None);
+        assert_eq!((..10).just_above(), Some(10));
+        assert_eq!((3..).just_above(), None);
+        assert_eq!(RangeBounds::<i32>::just_below(&..), None);
+
+        assert_eq!((i8::MIN..0).just_below(), None);
+        assert_eq!((Excluded(i8::MIN), Unbounded).just_below(), Some(i8::MIN));
+        assert_eq!((0..=u8::MAX).just_above(), None);
+        assert_eq!((Unbounded, Excluded(u8::MAX)).just_above(), Some(u8::MAX));
+
+        for range in small_ranges() {
+            if let Some(below) = range.just_below() {
+                assert!(!RangeBounds::contains(&range, &below), "{range:?}");
+                if let Some(min) = range.min_value() {
+                    assert_eq!(below + 1, min, "{range:?}");
+                }
+            }
+            if let Some(above) = range.just_above() {
+                assert!(!RangeBounds::contains(&range, &above), "{range:?}");
+                if let Some(max) = range.max_value() {
+                    assert_eq!(above - 1, max, "{range:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn with_inclusive_start_and_exclusive_end() {
+        use Bound::*;
+
+        let range = (Excluded(3), Included(7));
+        assert_eq!(range.with_inclusive_start(), (Included(4), Included(7)));
+        assert_eq!(range.with_exclusive_end(), (Excluded(3), Excluded(8)));
+        assert_eq!(
+            range.with_inclusive_start().with_exclusive_end(),
+            (Included(4), Excluded(8))
+        );
+        assert_eq!((3..5).with_inclusive_start(), (Included(3), Excluded(5)));
+        assert_eq!((3..5).with_exclusive_end(), (Included(3), Excluded(5)));
+        assert_eq!((..=5).with_exclusive_end(), (Unbounded, Excluded(6)));
+        assert_eq!(
+            (Excluded(5), Unbounded).with_inclusive_start(),
+            (Included(6), Unbounded)
+        );
+
+        // The greatest value has nothing after it to switch to.
+        assert_eq!(
+            (Excluded(u8::MAX), Unbounded).with_inclusive_start(),
+            (Excluded(u8::MAX), Unbounded)
+        );
+        assert_eq!(
+            (Included(0), Included(u8::MAX)).with_exclusive_end(),
+            (Included(0), Included(u8::MAX))
+        );
+
+        for range in small_ranges() {
+            let rewritten = range.with_inclusive_start().with_exclusive_end();
+            assert!(!matches!(rewritten.0, Excluded(_)), "{range:?}");
+            assert!(!matches!(rewritten.1, Included(_)), "{range:?}");
+            for value in -1..=4 {
+                assert_eq!(
+                    RangeBounds::contains(&rewritten, &value),
+                    RangeBounds::contains(&range, &value),
+                    "{range:?} {value}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn locate_at_endpoints() {
+        use Bound::*;
+        use PointPosition::*;
+
+        assert_eq!((Excluded(3), Excluded(5)).locate(&3), Below);
+        assert_eq!((Excluded(3), Excluded(5)).locate(&4), Inside);
+        assert_eq!((Excluded(3), Excluded(5)).locate(&5), Above);
+        assert_eq!((Included(3), Included(5)).locate(&3), Inside);
+        assert_eq!((Included(3), Included(5)).locate(&5), Inside);
+        assert_eq!((Included(3), Included(5)).locate(&6), Above);
+        assert_eq!((3..).locate(&i32::MAX), Inside);
+        assert_eq!((..=5).locate(&i32::MIN), Inside);
+        assert_eq!(RangeBounds::<i32>::locate(&.., &0), Inside);
+        assert_eq!((0.0..1.0).locate(&f64::NAN), Below);
+        assert_eq!((5..5).locate(&5), Above);
+
+        for range in small_ranges() {
+            for point in -1..=4 {
+                let position = range.locate(&point);
+                assert_eq!(
+                    position == Inside,
+                    RangeBounds::contains(&range, &point),
+                    "{point} and {range:?}"
+                );
+                assert!(range.is_bounded_below() || position != Below);
+                assert!(range.is_bounded_above() || position != Above);
+            }
+        }
+    }
+
+    #[test]
+    fn is_bounded() {
+        use Bound::*;
+
+        for range in small_ranges() {
+            assert_eq!(range.is_bounded_below(), range.0 != Unbounded);
+            assert_eq!(range.is_bounded_above(), range.1 != Unbounded);
+            assert_eq!(
+                range.is_bounded(),
+                range.0 != Unbounded && range.1 != Unbounded
+            );
+        }
+        assert!((3..=5).is_bounded());
+        assert!((..=5).is_bounded_above());
+        assert!(!(..=5).is_bounded_below());
+        assert!((Included(5), Excluded(3)).is_bounded());
+        assert!(!RangeBounds::<i32>::is_bounded_below(&..));
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(RangeBounds::is_empty(&(3..3)));
+        assert!(RangeBounds::<i32>::is_empty(&(
+            Bound::Included(5),
+            Bound::Excluded(3)
+        )));
+        assert!(!RangeBounds::is_empty(&(3..4)));
+        assert!(!RangeBounds::is_empty(&(3..=3)));
+        assert!(RangeBounds::<i32>::is_empty(&(
+            Bound::Included(3),
+            Bound::Excluded(3)
+        )));
+        assert!(!RangeBounds::<i32>::is_empty(&(
+            Bound::Included(3),
+            Bound::Included(3)
+        )));
+        assert!(RangeBounds::<i32>::is_empty(&(
+            Bound::Excluded(3),
+            Bound::Excluded(3)
+        )));
+        assert!(RangeBounds::<i32>::is_empty(&(
+            Bound::Excluded(3),
+            Bound::Included(3)
+        )));
+        assert!(!RangeBounds::<i32>::is_empty(&(
+            Bound::Excluded(3),
+            Bound::Excluded(4)
+        )));
+        assert!(!RangeBounds::<i32>::is_empty(&(..3)));
+        assert!(!RangeBounds::<i32>::is_empty(&(3..)));
+        assert!(!RangeBounds::<i32>::is_empty(&..));
+    }
+
+    #[test]
+    fn normalize() {
+        use Bound::*;
+
+        assert_eq!((Included(5), Excluded(3)).normalize(), None);
+        assert_eq!((5..5).normalize(), None);
+        assert_eq!((Excluded(5), Included(5)).normalize(), None);
+        assert_eq!((Excluded(5), Excluded(5)).normalize(), None);
+        assert_eq!((f64::NAN..).normalize(), None);
+        assert_eq!((3..5).normalize(), Some((Included(3), Excluded(5))));
+        assert_eq!((5..=5).normalize(), Some((Included(5), Included(5))));
+        assert_eq!(
+            (Excluded(2), Excluded(5)).normalize(),
+            Some((Excluded(2), Excluded(5)))
+        );
+        assert_eq!((..5).normalize(), Some((Unbounded, Excluded(5))));
+
+        for range in small_ranges() {
+            match range.normalize() {
+                Some(normal) => assert_eq!(normal, range),
+                None => assert!(range.is_empty()),
+            }
+        }
+    }
+
+    #[test]
+    fn is_single_point() {
+        use Bound::*;
+
+        assert_eq!((5..=5).is_single_point(), Some(&5));
+        assert_eq!((Included(5), Included(5)).is_single_point(), Some(&5));
+        assert_eq!((5..6).is_single_point(), None);
+        assert_eq!((5..5).is_single_point(), None);
+        assert_eq!((Excluded(4), Excluded(6)).is_single_point(), None);
+        assert_eq!((Excluded(5), Included(5)).is_single_point(), None);
+        assert_eq!((Included(5), Excluded(5)).is_single_point(), None);
+        assert_eq!((Included(6), Included(5)).is_single_point(), None);
+        assert_eq!((Unbounded, Included(5)).is_single_point(), None);
+        assert_eq!((0.5..=0.5).is_single_point(), Some(&0.5));
+        assert_eq!((f64::NAN..=f64::NAN).is_single_point(), None);
+        assert_eq!((&"a"..=&"a").is_single_point(), Some(&"a"));
+    }
+
+    #[test]
+    fn try_overlaps() {
+        use Bound::*;
+
+        assert_eq!((0.0..1.0).try_overlaps(&(0.5..2.0)), Ok(true));
+        assert_eq!((0.0..1.0).try_overlaps(&(1.0..2.0)), Ok(false));
+        assert_eq!((0.0..=1.0).try_overlaps(&(1.0..2.0)), Ok(true));
+        assert_eq!((5.0..5.0).try_overlaps(&(0.0..10.0)), Ok(false));
+        assert_eq!(
+            (Excluded(1.0), Unbounded).try_overlaps(&(..=1.0)),
+            Ok(false)
+        );
+        for a in small_ranges() {
+            for b in small_ranges() {
+                assert_eq!(a.try_overlaps(&b), Ok(a.overlaps(&b)), "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn is_disjoint() {
+        use Bound::*;
+
+        assert!((3..5).is_disjoint(&(5..7)));
+        assert!((0..3).is_disjoint(&(Excluded(3), Excluded(5))));
+        assert!(!(3..5).is_disjoint(&(1..4)));
+        assert!(!(0..=3).is_disjoint(&(3..5)));
+        assert!((5..5).is_disjoint(&(0..10)));
+        assert!((0..10).is_disjoint(&(5..5)));
+        assert!((5..5).is_disjoint(&(5..5)));
+        assert!((Included(7), Excluded(3)).is_disjoint(&(..)));
+        for a in small_ranges() {
+            for b in small_ranges() {
+                assert_eq!(a.is_disjoint(&b), !a.overlaps(&b));
+                if a.is_empty() || b.is_empty() {
+                    assert!(a.is_disjoint(&b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_before_and_after() {
+        use Bound::*;
+
+        assert!((0..3).is_before(&(3..5)));
+        assert!((0..=3).is_before(&(Excluded(3), Excluded(5))));
+        assert!((0..3).is_before(&(Excluded(3), Excluded(5))));
+        assert!(!(0..3).is_before(&(Included(2), Excluded(5))));
+        assert!(!(0..=3).is_before(&(3..5)));
+        assert!(!(3..5).is_before(&(0..3)));
+        assert!(!(..3).is_before(&(..)));
+        assert!((..3).is_before(&(4..)));
+        assert!(!(5..5).is_before(&(6..7)));
+        assert!(!(0..1).is_before(&(6..6)));
+
+        assert!((3..5).is_after(&(0..3)));
+        assert!(!(2..5).is_after(&(0..3)));
+        assert!(!(0..3).is_after(&(3..5)));
+
+        for a in small_ranges() {
+            for b in small_ranges() {
+                let points = |range: &(Bound<i32>, Bound<i32>)| {
+                    let range = range.map(|&x| x as f64);
+                    (-4..=12)
+                        .map(|x| x as f64 / 2.0)
+                        .filter(|x| range.contains(x))
+                        .collect::<Vec<_>>()
+                };
+                let (in_a, in_b) = (points(&a), points(&b));
+                let expected = !in_a.is_empty()
+                    && !in_b.is_empty()
+                    && in_a.iter().all(|x| in_b.iter().all(|y| x < y));
+                assert_eq!(a.is_before(&b), expected, "{a:?} before {b:?}");
+                assert_eq!(a.is_before(&b), b.is_after(&a), "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn touches() {
+        assert!((0..3).touches(&(3..5)));
+        assert!((3..5).touches(&(0..3)));
+        assert!((0..=3).touches(&(Bound::Excluded(3), Bound::Included(5))));
+        assert!((..3).touches(&(3..)));
+        assert!(!(0..3).touches(&(Bound::Excluded(3), Bound::Excluded(5))));
+        assert!(!(0..=3).touches(&(3..5)));
+        assert!(!(0..=3).touches(&(4..5)));
+        assert!(!(0..3).touches(&(2..5)));
+        assert!(!(0..10).touches(&(3..5)));
+        assert!(!(3..3).touches(&(3..5)));
+        assert!(!(0..3).touches(&(3..3)));
+    }
+
+    #[test]
+    fn relation() {
+        use IntervalRelation::*;
+
+        let cases = [
+            ((0, 3), (5, 8), Before),
+            ((0, 3), (3, 8), Meets),
+            ((0, 5), (3, 8), Overlaps),
+            ((0, 3), (0, 8), Starts),
+            ((3, 5), (0, 8), During),
+            ((5, 8), (0, 8), Finishes),
+            ((0, 8), (0, 8), Equals),
+            ((0, 8), (0, 3), StartedBy),
+            ((0, 8), (3, 5), Contains),
+            ((0, 8), (5, 8), FinishedBy),
+            ((3, 8), (0, 5), OverlappedBy),
+            ((3, 8), (0, 3), MetBy),
+            ((5, 8), (0, 3), After),
+        ];
+        for ((a_start, a_end), (b_start, b_end), expected) in cases {
+            assert_eq!((a_start..a_end).relation(&(b_start..b_end)), Some(expected));
+            assert_eq!(
+                (b_start..b_end).relation(&(a_start..a_end)),
+                Some(expected.inverse())
+            );
+        }
+
+        assert_eq!((0..=3).relation(&(3..8)), Some(Overlaps));
+        assert_eq!((0..=3).relation(&(0..3)), Some(StartedBy));
+        assert_eq!(
+            (0..3).relation(&(Bound::Excluded(3), Bound::Excluded(8))),
+            Some(Before)
+        );
+        assert_eq!((..5).relation(&(3..)), Some(Overlaps));
+        assert_eq!((..5).relation(&(..3)), Some(StartedBy));
+        assert_eq!((..3).relation(&(5..)), Some(Before));
+        assert_eq!((..3).relation(&(3..)), Some(Meets));
+        assert_eq!((3..).relation(&(..3)), Some(MetBy));
+        assert_eq!((3..).relation(&(0..)), Some(Finishes));
+        assert_eq!(RangeBounds::<i32>::relation(&.., &(3..5)), Some(Contains));
+        assert_eq!(RangeBounds::<i32>::relation(&.., &..), Some(Equals));
+        assert_eq!((5..5).relation(&(0..8)), None);
+        assert_eq!((0.0..1.0).relation(&(f64::NAN..2.0)), None);
+    }
+
+    #[test]
+    fn contains_range() {
+        assert!((0..10).contains_range(&(2..5)));
+        assert!((0..10).contains_range(&(0..10)));
+        assert!(!(0..10).contains_range(&(5..12)));
+        assert!(!(0..10).contains_range(&(-1..5)));
+        assert!((0..10).contains_range(&(0..=9)));
+        assert!(!(0..=9).contains_range(&(0..10)));
+        assert!((0..=10).contains_range(&(0..10)));
+        assert!(!(0..10).contains_range(&(0..=10)));
+        assert!((0..10).contains_range(&(Bound::Excluded(0), Bound::Excluded(10))));
+        assert!(!(Bound::Excluded(0), Bound::Excluded(10)).contains_range(&(0..10)));
+        assert!(RangeBounds::<i32>::contains_range(&.., &(3..5)));
+        assert!(RangeBounds::<i32>::contains_range(&.., &..));
+        assert!((3..).contains_range(&(5..)));
+        assert!(!(3..).contains_range(&(..5)));
+        assert!((..5).contains_range(&(..=4)));
+        assert!(!(3..5).contains_range(&(3..)));
+        assert!((0..1).contains_range(&(5..5)));
+        assert!(!(5..5).contains_range(&(5..=5)));
+    }
+
+    #[test]
+    fn contains_range_with_excluded_endpoints() {
+        use Bound::*;
+
+        // A range can enclose `other` without containing `other`'s excluded
+        // endpoints, so probing those endpoints with `contains` would give
+        // the wrong answer.
+        assert!((0..10).contains_range(&(Excluded(0), Included(9))));
+        assert!((Excluded(0), Excluded(10)).contains_range(&(Excluded(0), Excluded(10))));
+        assert!((Excluded(0), Included(10)).contains_range(&(Excluded(0), Excluded(10))));
+        assert!(!(Excluded(0), Excluded(10)).contains_range(&(Excluded(0), Included(10))));
+        assert!((..10).contains_range(&(Included(5), Excluded(10))));
+
+        for a in small_ranges() {
+            for b in small_ranges() {
+                if a.contains_range(&b) {
+                    for point in -1..=4 {
+                        assert!(
+                            !RangeBounds::contains(&b, &point) || RangeBounds::contains(&a, &point),
+                            "{a:?} and {b:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gap() {
+        assert_eq!(
+            (0..3).gap(&(5..8)),
+            Some((Bound::Included(3), Bound::Excluded(5)))
+        );
+        assert_eq!(
+            (5..8).gap(&(0..3)),
+            Some((Bound::Included(3), Bound::Excluded(5)))
+        );
+        assert_eq!(
+            (0..=3).gap(&(Bound::Excluded(5), Bound::Included(8))),
+            Some((Bound::Excluded(3), Bound::Included(5)))
+        );
+        assert_eq!(
+            (0..3).gap(&(Bound::Excluded(3), Bound::Excluded(5))),
+            Some((Bound::Included(3), Bound::Included(3)))
+        );
+        assert_eq!(
+            (..3).gap(&(5..)),
+            Some((Bound::Included(3), Bound::Excluded(5)))
+        );
+        assert_eq!((0..3).gap(&(3..8)), None);
+        assert_eq!((0..3).gap(&(2..8)), None);
+        assert_eq!((0..3).gap(&(5..5)), None);
+    }
+
+    #[test]
+    fn symmetric_difference() {
+        use Bound::*;
+
+        // Disjoint ranges come back unchanged, lower first.
+        assert_eq!(
+            (5..8).symmetric_difference(&(0..3)),
+            (
+                Some((Included(0), Excluded(3))),
+                Some((Included(5), Excluded(8)))
+            )
+        );
+        assert_eq!(
+            (0..3).symmetric_difference(&(3..8)),
+            (
+                Some((Included(0), Excluded(3))),
+                Some((Included(3), Excluded(8)))
+            )
+        );
+        // Overlapping ranges lose their shared part.
+        assert_eq!(
+            (0..5).symmetric_difference(&(3..10)),
+            (
+                Some((Included(0), Excluded(3))),
+                Some((Included(5), Excluded(10)))
+            )
+        );
+        assert_eq!(
+            (0..=5).symmetric_difference(&(Excluded(3), Included(10))),
+            (
+                Some((Included(0), Included(3))),
+                Some((Excluded(5), Included(10)))
+            )
+        );
+        // An enclosing range splits around the enclosed one.
+        assert_eq!(
+            (3..5).symmetric_difference(&(0..10)),
+            (
+                Some((Included(0), Excluded(3))),
+                Some((Included(5), Excluded(10)))
+            )
+        );
+        assert_eq!(
+            (0..=10).symmetric_difference(&(Excluded(0), Excluded(10))),
+            (
+                Some((Included(0), Included(0))),
+                Some((Included(10), Included(10)))
+            )
+        );
+        assert_eq!(
+            (..).symmetric_difference(&(3..5)),
+            (
+                Some((Unbounded, Excluded(3))),
+                Some((Included(5), Unbounded))
+            )
+        );
+        assert_eq!(
+            (0..10).symmetric_difference(&(0..5)),
+            (None, Some((Included(5), Excluded(10))))
+        );
+        assert_eq!(
+            (0..10).symmetric_difference(&(5..10)),
+            (Some((Included(0), Excluded(5))), None)
+        );
+        assert_eq!((0..10).symmetric_difference(&(0..10)), (None, None));
+        // Empty ranges cover nothing.
+        assert_eq!(
+            (0..10).symmetric_difference(&(5..5)),
+            (Some((Included(0), Excluded(10))), None)
+        );
+        assert_eq!((5..5).symmetric_difference(&(7..7)), (None, None));
+    }
+
+    #[test]
+    fn difference() {
+        use Bound::*;
+
+        // No overlap.
+        assert_eq!(
+            (0..3).difference(&(5..8)),
+            (Some((Included(0), Excluded(3))), None)
+        );
+        assert_eq!(
+            (0..3).difference(&(3..8)),
+            (Some((Included(0), Excluded(3))), None)
+        );
+        // Left overlap.
+        assert_eq!(
+            (0..10).difference(&(-5..3)),
+            (None, Some((Included(3), Excluded(10))))
+        );
+        assert_eq!(
+            (0..10).difference(&(..=3)),
+            (None, Some((Excluded(3), Excluded(10))))
+        );
+        // Right overlap.
+        assert_eq!(
+            (0..10).difference(&(5..15)),
+            (Some((Included(0), Excluded(5))), None)
+        );
+        assert_eq!(
+            (0..=10).difference(&(Excluded(5), Excluded(10))),
+            (
+                Some((Included(0), Included(5))),
+                Some((Included(10), Included(10)))
+            )
+        );
+        // Interior hole.
+        assert_eq!(
+            (0..10).difference(&(3..5)),
+            (
+                Some((Included(0), Excluded(3))),
+                Some((Included(5), Excluded(10)))
+            )
+        );
+        assert_eq!(
+            (..).difference(&(3..=5)),
+            (
+                Some((Unbounded, Excluded(3))),
+                Some((Excluded(5), Unbounded))
+            )
+        );
+        // Fully covered.
+        assert_eq!((3..5).difference(&(0..10)), (None, None));
+        assert_eq!((3..5).difference(&(3..5)), (None, None));
+        // Empty ranges.
+        assert_eq!(
+            (0..10).difference(&(5..5)),
+            (Some((Included(0), Excluded(10))), None)
+        );
+        assert_eq!((5..5).difference(&(0..10)), (None, None));
+    }
+
+    #[test]
+    fn complement_within() {
+        use Bound::*;
+
+        // A hole in the middle flips the kinds of the cut bounds.
+        assert_eq!(
+            (3..=5).complement_within(&(0..10)),
+            (
+                Some((Included(0), Excluded(3))),
+                Some((Excluded(5), Excluded(10)))
+            )
+        );
+        assert_eq!(
+            (Excluded(3), Excluded(5)).complement_within(&(..)),
+            (
+                Some((Unbounded, Included(3))),
+                Some((Included(5), Unbounded))
+            )
+        );
+        // Touching or passing one edge leaves one fragment.
+        assert_eq!(
+            (0..5).complement_within(&(0..10)),
+            (None, Some((Included(5), Excluded(10))))
+        );
+        assert_eq!(
+            (-5..=5).complement_within(&(0..=10)),
+            (None, Some((Excluded(5), Included(10))))
+        );
+        assert_eq!(
+            (5..).complement_within(&(0..10)),
+            (Some((Included(0), Excluded(5))), None)
+        );
+        assert_eq!(
+            (Excluded(0), Excluded(10)).complement_within(&(0..=10)),
+            (
+                Some((Included(0), Included(0))),
+                Some((Included(10), Included(10)))
+            )
+        );
+        // Covering the domain leaves nothing.
+        assert_eq!((0..10).complement_within(&(0..10)), (None, None));
+        assert_eq!((-5..15).complement_within(&(0..10)), (None, None));
+        assert_eq!((..).complement_within(&(5..5)), (None, None));
+        // Missing the domain leaves all of it.
+        assert_eq!(
+            (10..20).complement_within(&(0..10)),
+            (Some((Included(0), Excluded(10))), None)
+        );
+        assert_eq!(
+            (..0).complement_within(&(0..10)),
+            (Some((Included(0), Excluded(10))), None)
+        );
+        assert_eq!(
+            (5..5).complement_within(&(0..10)),
+            (Some((Included(0), Excluded(10))), None)
+        );
+    }
+
+    #[test]
+    fn bounding_range() {
+        use Bound::*;
+
+        assert_eq!((0..3).bounding_range(&(7..9)), (Included(0), Excluded(9)));
+        assert_eq!((7..9).bounding_range(&(0..3)), (Included(0), Excluded(9)));
+        assert_eq!((0..5).bounding_range(&(3..=9)), (Included(0), Included(9)));
+        assert_eq!((0..10).bounding_range(&(3..5)), (Included(0), Excluded(10)));
+        assert_eq!(
+            (0..=3).bounding_range(&(Excluded(0), Excluded(3))),
+            (Included(0), Included(3))
+        );
+        assert_eq!((..3).bounding_range(&(7..9)), (Unbounded, Excluded(9)));
+        assert_eq!((0..3).bounding_range(&(7..)), (Included(0), Unbounded));
+        assert_eq!((5..5).bounding_range(&(0..3)), (Included(0), Excluded(3)));
+        assert_eq!((0..3).bounding_range(&(5..5)), (Included(0), Excluded(3)));
+        assert!((5..5).bounding_range(&(7..7)).is_empty());
+
+        for a in small_ranges() {
+            for b in small_ranges() {
+                let hull = a.bounding_range(&b);
+                assert!(hull.contains_range(&a), "{hull:?} encloses {a:?}");
+                assert!(hull.contains_range(&b), "{hull:?} encloses {b:?}");
+                if let Some(union) = a.union(&b) {
+                    assert_eq!(hull, union, "{a:?} and {b:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn map() {
+        use Bound::*;
+
+        assert_eq!(
+            RangeBounds::map(&(3..10), |&x| x * 1000),
+            (Included(3000), Excluded(10000))
+        );
+        assert_eq!(
+            RangeBounds::map(&(3..), |&x| x * 1000),
+            (Included(3000), Unbounded)
+        );
+        assert_eq!(
+            (Excluded(1.5), Included(2.5)).map(|&x| x as i32),
+            (Excluded(1), Included(2))
+        );
+        assert_eq!(
+            RangeBounds::<i32>::map(&.., |&x| x + 1),
+            (Unbounded, Unbounded)
+        );
+        assert_eq!(
+            RangeBounds::map(&("a"..="b"), |s: &&str| s.len()),
+            (Included(1), Included(1))
+        );
+    }
+
+    #[test]
+    fn offset() {
+        use Bound::*;
+
+        assert_eq!((3..10).offset(5), (Included(8), Excluded(15)));
+        assert_eq!((3..10).offset(-5), (Included(-2), Excluded(5)));
+        assert_eq!((3..=10).offset(0), (Included(3), Included(10)));
+        assert_eq!(
+            (Excluded(-3), Unbounded).offset(-4),
+            (Excluded(-7), Unbounded)
+        );
+        assert_eq!(RangeBounds::<i32>::offset(&.., 5), (Unbounded, Unbounded));
+        assert_eq!((0.5..1.5).offset(0.25), (Included(0.75), Excluded(1.75)));
+    }
+
+    #[test]
+    fn checked_offset() {
+        use Bound::*;
+
+        assert_eq!((3..10).checked_offset(5), Some((Included(8), Excluded(15))));
+        assert_eq!(
+            (3..10).checked_offset(-5),
+            Some((Included(-2), Excluded(5)))
+        );
+        assert_eq!(
+            (..=i8::MAX - 1).checked_offset(1),
+            Some((Unbounded, Included(i8::MAX)))
+        );
+        assert_eq!((..=i8::MAX).checked_offset(1), None);
+        assert_eq!((i8::MIN..0).checked_offset(-1), None);
+        assert_eq!((Excluded(0u8), Excluded(5)).checked_offset(u8::MAX), None);
+        assert_eq!(
+            RangeBounds::<i32>::checked_offset(&.., i32::MAX),
+            Some((Unbounded, Unbounded))
+        );
+    }
+
+    #[test]
+    fn saturating_offset() {
+        use Bound::*;
+
+        assert_eq!((3..10).saturating_offset(5), (Included(8), Excluded(15)));
+        assert_eq!(
+            (Excluded(250u8), Included(252)).saturating_offset(4),
+            (Excluded(254), Included(255))
+        );
+        assert_eq!(
+            (i8::MIN + 1..0).saturating_offset_sub(5),
+            (Included(i8::MIN), Excluded(-5))
+        );
+        assert_eq!((2u8..).saturating_offset_sub(3), (Included(0), Unbounded));
+        assert_eq!(
+            RangeBounds::<u8>::saturating_offset(&.., u8::MAX),
+            (Unbounded, Unbounded)
+        );
+    }
+
+    #[test]
+    fn scale() {
+        use Bound::*;
+
+        assert_eq!((2..6).scale(3), (Included(6), Excluded(18)));
+        assert_eq!((2..6).scale(-1), (Excluded(-6), Included(-2)));
+        assert_eq!(
+            (Excluded(-2), Included(4)).scale(-2),
+            (Included(-8), Excluded(4))
+        );
+        assert_eq!((..6).scale(-2), (Excluded(-12), Unbounded));
+        assert_eq!((3..).scale(-2), (Unbounded, Included(-6)));
+        assert_eq!((3..).scale(2), (Included(6), Unbounded));
+        assert_eq!((2..6).scale(0), (Included(0), Excluded(0)));
+        assert_eq!((1.0..=2.0).scale(0.5), (Included(0.5), Included(1.0)));
+
+        for range in small_ranges() {
+            let mirrored = range.scale(-1);
+            for value in -5..=5 {
+                assert_eq!(
+                    range.contains(&value),
+                    mirrored.contains(&-value),
+                    "{range:?} at {value}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn split_at() {
+        use Bound::*;
+
+        assert_eq!(
+            (0..10).split_at(5),
+            ((Included(0), Excluded(5)), (Included(5), Excluded(10)))
+        );
+        assert_eq!(
+            (0..10).split_at(0),
+            ((Included(0), Excluded(0)), (Included(0), Excluded(10)))
+        );
+        assert_eq!(
+            (0..10).split_at(10),
+            ((Included(0), Excluded(10)), (Included(10), Excluded(10)))
+        );
+        assert_eq!(
+            (0..10).split_at(-5),
+            ((Included(0), Excluded(-5)), (Included(0), Excluded(10)))
+        );
+        assert_eq!(
+            (Excluded(0), Included(10)).split_at(0),
+            ((Excluded(0), Excluded(0)), (Excluded(0), Included(10)))
+        );
+        assert_eq!(
+            (Excluded(0), Included(10)).split_at(10),
+            ((Excluded(0), Excluded(10)), (Included(10), Included(10)))
+        );
+        assert_eq!(
+            RangeBounds::<i32>::split_at(&.., 3),
+            ((Unbounded, Excluded(3)), (Included(3), Unbounded))
+        );
+
+        for range in small_ranges() {
+            for pivot in -1..=4 {
+                let (below, above) = range.split_at(pivot);
+                for value in -2..=5 {
+                    assert_eq!(
+                        range.contains(&value),
+                        below.contains(&value) || above.contains(&value),
+                        "{range:?} split at {pivot}, value {value}"
+                    );
+                    assert!(!(below.contains(&value) && above.contains(&value)));
+                    assert!(!below.contains(&value) || value < pivot);
+                    assert!(!above.contains(&value) || value >= pivot);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn intersection() {
+        use Bound::*;
+
+        assert_eq!(
+            (0..10).intersection(&(5..15)),
+            Some((Included(5), Excluded(10)))
+        );
+        assert_eq!(
+            (0..10).intersection(&(2..5)),
+            Some((Included(2), Excluded(5)))
+        );
+        assert_eq!(
+            (0..=5).intersection(&(Excluded(0), Included(5))),
+            Some((Excluded(0), Included(5)))
+        );
+        assert_eq!(
+            (0..=5).intersection(&(5..)),
+            Some((Included(5), Included(5)))
+        );
+        assert_eq!((..5).intersection(&(3..)), Some((Included(3), Excluded(5))));
+        assert_eq!((0..5).intersection(&(5..10)), None);
+        assert_eq!((0..5).intersection(&(7..10)), None);
+        assert_eq!((3..3).intersection(&(0..10)), None);
+
+        for a in small_ranges() {
+            for b in small_ranges() {
+                match a.intersection(&b) {
+                    Some(both) => {
+                        for value in -1..=4 {
+                            assert_eq!(
+                                both.contains(&value),
+                                a.contains(&value) && b.contains(&value),
+                                "{a:?} and {b:?} at {value}"
+                            );
+                        }
+                    }
+                    None => assert!(!a.overlaps(&b), "{a:?} and {b:?}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clamp_range() {
+        use Bound::*;
+
+        assert_eq!(
+            (-5..100).clamp_range(&(0..50)),
+            Some((Included(0), Excluded(50)))
+        );
+        assert_eq!(
+            (10..=20).clamp_range(&(0..50)),
+            Some((Included(10), Included(20)))
+        );
+        assert_eq!(
+            (Excluded(0), Unbounded).clamp_range(&(0..=50)),
+            Some((Excluded(0), Included(50)))
+        );
+        assert_eq!(
+            (..).clamp_range(&(0..50)),
+            Some((Included(0), Excluded(50)))
+        );
+        assert_eq!((60..70).clamp_range(&(0..50)), None);
+        assert_eq!((50..70).clamp_range(&(0..50)), None);
+    }
+
+    #[test]
+    fn saturating_within() {
+        use Bound::*;
+
+        let domain = 0..100;
+        assert_eq!(
+            RangeBounds::<i32>::saturating_within(&.., &domain),
+            Some((Included(0), Excluded(100)))
+        );
+        assert_eq!(
+            (..=50).saturating_within(&domain),
+            Some((Included(0), Included(50)))
+        );
+        assert_eq!(
+            (Excluded(50), Unbounded).saturating_within(&domain),
+            Some((Excluded(50), Excluded(100)))
+        );
+        assert_eq!(
+            (-10..200).saturating_within(&domain),
+            Some((Included(0), Excluded(100)))
+        );
+        assert_eq!(
+            (20..30).saturating_within(&domain),
+            Some((Included(20), Excluded(30)))
+        );
+        assert_eq!((..0).saturating_within(&domain), None);
+        assert_eq!((100..).saturating_within(&domain), None);
+    }
+
+    #[test]
+    fn overlaps_within() {
+        use Bound::*;
+
+        assert!((0.0..1.0).overlaps_within(&(1.0000001..2.0), 1e-5));
+        assert!((1.0000001..2.0).overlaps_within(&(0.0..1.0), 1e-5));
+        assert!(!(0.0..1.0).overlaps_within(&(1.1..2.0), 1e-5));
+        assert!((0.0..1.0).overlaps_within(&(0.5..2.0), 0.0));
+        assert!((0.0..1.0).overlaps_within(&(1.0..2.0), 1e-9));
+        assert!(!(0.0..1.0).overlaps_within(&(1.0..2.0), 0.0));
+        assert!(!(0.0..1.0).overlaps_within(&(1.0..2.0), -1.0));
+        assert!((..1.0).overlaps_within(&(Excluded(1.5), Unbounded), 0.6));
+        assert!(!(..1.0).overlaps_within(&(Excluded(1.5), Unbounded), 0.5));
+
+        assert!(!(0.0..1.0).overlaps_within(&(1.0..2.0), f64::NAN));
+        assert!(!(0.0..1.0).overlaps_within(&(0.5..2.0), f64::NAN));
+        assert!(!(0.0..f64::NAN).overlaps_within(&(0.5..2.0), 1.0));
+        assert!(!(1.0..1.0).overlaps_within(&(1.0..2.0), 1.0));
+
+        // Gaps too wide for the type go to infinity instead of overflowing.
+        assert!(!(f64::MIN..=f64::MIN).overlaps_within(&(f64::MAX..=f64::MAX), 1.0));
+        assert!(!(f64::MAX..=f64::MAX).overlaps_within(&(f64::MIN..=f64::MIN), f64::MAX));
+        let lowest = f32::MIN..=f32::MIN;
+        assert!(!lowest.overlaps_within(&(f32::MAX..=f32::MAX), f32::INFINITY));
+        let below_infinity = (Unbounded, Excluded(f64::INFINITY));
+        assert!(below_infinity.overlaps_within(&(f64::INFINITY..), 1e-9));
+        assert!(!below_infinity.overlaps_within(&(f64::INFINITY..), 0.0));
+        assert!((0.0f32..1.0).overlaps_within(&(1.00001..2.0), 1e-3));
+    }
+
+    #[test]
+    fn partial_cmp_by_inclusion() {
+        use Bound::*;
+        use Ordering::*;
+
+        assert_eq!((2..5).partial_cmp_by_inclusion(&(0..10)), Some(Less));
+        assert_eq!((0..10).partial_cmp_by_inclusion(&(2..5)), Some(Greater));
+        assert_eq!((0..10).partial_cmp_by_inclusion(&(0..=9)), Some(Greater));
+        assert_eq!((..5).partial_cmp_by_inclusion(&(..)), Some(Less));
+        assert_eq!(
+            (0..10).partial_cmp_by_inclusion(&(Included(0), Excluded(10))),
+            Some(Equal)
+        );
+        assert_eq!(
+            (Excluded(0), Excluded(10)).partial_cmp_by_inclusion(&(0..10)),
+            Some(Less)
+        );
+        assert_eq!((0..3).partial_cmp_by_inclusion(&(5..8)), None);
+        assert_eq!((0..3).partial_cmp_by_inclusion(&(3..8)), None);
+        assert_eq!((0..5).partial_cmp_by_inclusion(&(3..8)), None);
+        assert_eq!((..5).partial_cmp_by_inclusion(&(3..)), None);
+
+        assert_eq!(
+            (3..3).partial_cmp_by_inclusion(&(Included(7), Included(2))),
+            Some(Equal)
+        );
+        assert_eq!((3..3).partial_cmp_by_inclusion(&(20..30)), Some(Less));
+
+        for a in small_ranges() {
+            for b in small_ranges() {
+                assert_eq!(
+                    a.partial_cmp_by_inclusion(&b),
+                    b.partial_cmp_by_inclusion(&a).map(Ordering::reverse),
+                    "{a:?} and {b:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn same_set() {
+        use Bound::*;
+
+        assert!((0..0).same_set(&(5..5)));
+        assert!((0..0).same_set(&(Included(9), Included(2))));
+        assert!((0..5).same_set(&(Included(0), Excluded(5))));
+        assert!((..).same_set(&(Unbounded::<i32>, Unbounded)));
+        assert!((3..=3).same_set(&(Included(3), Included(3))));
+        assert!(!(0..5).same_set(&(0..=5)));
+        assert!(!(0..5).same_set(&(0..=4)));
+        assert!(!(0..5).same_set(&(Excluded(0), Excluded(5))));
+        assert!(!(0..5).same_set(&(5..5)));
+        assert!(!(0..).same_set(&(..)));
+
+        // Half steps stand in for the reals between integer endpoints.
+        let values = || (-2..=8).map(|i| f64::from(i) / 2.0);
+        let ranges = small_ranges();
+        for a in &ranges {
+            for b in &ranges {
+                let a = a.map(|&x| f64::from(x));
+                let b = b.map(|&x| f64::from(x));
+                let expected = values().all(|x| a.contains(&x) == b.contains(&x));
+                assert_eq!(a.same_set(&b), expected, "{a:?} and {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn overlap_amount() {
+        assert_eq!((0..10).overlap_amount(&(5..15)), Some(5));
+        assert_eq!((5..15).overlap_amount(&(0..10)), Some(5));
+        assert_eq!((0..10).overlap_amount(&(2..5)), Some(3));
+        assert_eq!((0..=5).overlap_amount(&(5..10)), Some(0));
+        assert_eq!((0..5).overlap_amount(&(5..10)), None);
+        assert_eq!((0..5).overlap_amount(&(7..10)), None);
+        assert_eq!((0..).overlap_amount(&(..4)), Some(4));
+        assert_eq!((0..).overlap_amount(&(5..)), None);
+    }
+
+    #[test]
+    fn union() {
+        assert_eq!(
+            (0..3).union(&(3..5)),
+            Some((Bound::Included(0), Bound::Excluded(5)))
+        );
+        assert_eq!(
+            (0..=3).union(&(Bound::Excluded(3), Bound::Included(5))),
+            Some((Bound::Included(0), Bound::Included(5)))
+        );
+        assert_eq!(
+            (0..3).union(&(Bound::Excluded(3), Bound::Excluded(5))),
+            None
+        );
+        assert_eq!((0..3).union(&(4..5)), None);
+        assert_eq!(
+            (0..10).union(&(2..5)),
+            Some((Bound::Included(0), Bound::Excluded(10)))
+        );
+        assert_eq!(
+            (2..5).union(&(Bound::Excluded(2), Bound::Included(5))),
+            Some((Bound::Included(2), Bound::Included(5)))
+        );
+        assert_eq!(
+            (..3).union(&(1..)),
+            Some((Bound::Unbounded, Bound::Unbounded))
+        );
+        assert_eq!(
+            (..3).union(&(1..5)),
+            Some((Bound::Unbounded, Bound::Excluded(5)))
+        );
+        assert_eq!(
+            (5..5).union(&(0..3)),
+            Some((Bound::Included(0), Bound::Excluded(3)))
+        );
+        assert_eq!((5..5).union(&(3..3)), None);
+    }
+}
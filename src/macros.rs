@@ -0,0 +1,201 @@
+use core::fmt;
+
+use crate::RangeBounds;
+
+/// Asserts that two ranges overlap, checking both `left.overlaps(&right)`
+/// and `right.overlaps(&left)`.
+///
+/// On failure the panic message shows both ranges in interval notation, as
+/// [`RangeBounds::display`](crate::RangeBounds::display) writes them, so
+/// the endpoint types must implement [`Display`](core::fmt::Display). If
+/// the two directions disagree, the message shows both results, since that
+/// points to a broken [`RangeBounds`](crate::RangeBounds) implementation
+/// rather than a wrong expectation. Like [`assert_eq!`], the macro takes an
+/// optional custom message after the two ranges.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{assert_disjoint, assert_overlaps};
+///
+/// assert_overlaps!(0..5, 4..);
+/// assert_disjoint!(0..5, 5.., "half-open ranges that only touch");
+/// ```
+///
+/// ```should_panic
+/// use rangebounds_overlaps::assert_overlaps;
+///
+/// // assertion `left overlaps right` failed
+/// //   left: [0, 5)
+/// //  right: [5, ∞)
+/// assert_overlaps!(0..5, 5..);
+/// ```
+#[macro_export]
+macro_rules! assert_overlaps {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::__assert_overlap(left, right, true, None),
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::__assert_overlap(
+                left,
+                right,
+                true,
+                Some(format_args!($($arg)+)),
+            ),
+        }
+    };
+}
+
+/// Asserts that two ranges do not overlap, checking both
+/// `left.overlaps(&right)` and `right.overlaps(&left)`.
+///
+/// This is the negation of [`assert_overlaps!`], with the same failure
+/// message format.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::assert_disjoint;
+/// use std::ops::Bound::*;
+///
+/// assert_disjoint!(..=0, (Excluded(0), Unbounded));
+/// assert_disjoint!(3..3, ..);
+/// ```
+#[macro_export]
+macro_rules! assert_disjoint {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::__assert_overlap(left, right, false, None),
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::__assert_overlap(
+                left,
+                right,
+                false,
+                Some(format_args!($($arg)+)),
+            ),
+        }
+    };
+}
+
+/// The body of [`assert_overlaps!`] and [`assert_disjoint!`], kept out of
+/// the macros so that the generic bounds are checked in one place.
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_overlap<T, A, B>(
+    left: &A,
+    right: &B,
+    expected: bool,
+    message: Option<fmt::Arguments>,
+) where
+    T: ?Sized + PartialOrd + fmt::Display,
+    A: RangeBounds<T>,
+    B: RangeBounds<T>,
+{
+    let forward = left.overlaps(right);
+    let backward = right.overlaps(left);
+    if forward == expected && backward == expected {
+        return;
+    }
+    let relation = if expected {
+        "overlaps"
+    } else {
+        "is disjoint from"
+    };
+    let left = left.display();
+    let right = right.display();
+    match message {
+        Some(message) => panic!(
+            "assertion `left {relation} right` failed: {message}\n  left: {left}\n right: {right}"
+        ),
+        None if forward != backward => panic!(
+            "assertion `left {relation} right` failed: `left.overlaps(&right)` is {forward} \
+             but `right.overlaps(&left)` is {backward}\n  left: {left}\n right: {right}"
+        ),
+        None => panic!("assertion `left {relation} right` failed\n  left: {left}\n right: {right}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound::{self, Excluded, Included, Unbounded};
+    use std::panic::{self, UnwindSafe};
+    use std::string::String;
+
+    use crate::RangeBounds;
+
+    fn panic_message(f: impl FnOnce() + UnwindSafe) -> String {
+        let payload = panic::catch_unwind(f).expect_err("the assertion passed");
+        match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => String::from(*payload.downcast::<&str>().unwrap()),
+        }
+    }
+
+    /// Overlaps going one way only, to exercise the asymmetric messages.
+    struct Lopsided;
+
+    impl RangeBounds<i32> for Lopsided {
+        fn start_bound(&self) -> Bound<&i32> {
+            Included(&0)
+        }
+
+        fn end_bound(&self) -> Bound<&i32> {
+            Excluded(&5)
+        }
+
+        fn overlaps<O, E>(&self, _other: &O) -> bool
+        where
+            i32: PartialOrd + PartialOrd<E>,
+            E: ?Sized + PartialOrd + PartialOrd<i32>,
+            O: RangeBounds<E>,
+        {
+            true
+        }
+    }
+
+    #[test]
+    fn passing_assertions() {
+        assert_overlaps!(0..5, 4..);
+        assert_overlaps!(..=0.5, 0.5..=0.5);
+        assert_overlaps!((Unbounded, Excluded(4)), 3..=3, "{} ranges", "one-sided");
+        assert_disjoint!(0..5, 5..);
+        assert_disjoint!(..=0, (Excluded(0), Unbounded),);
+        assert_disjoint!(2..2, .., "empty ranges overlap nothing");
+    }
+
+    #[test]
+    fn failure_messages() {
+        assert_eq!(
+            panic_message(|| assert_overlaps!(0..5, 5..)),
+            "assertion `left overlaps right` failed\n  left: [0, 5)\n right: [5, ∞)"
+        );
+        assert_eq!(
+            panic_message(|| assert_disjoint!(..=1.5, (Excluded(1.0), Included(2.0)))),
+            "assertion `left is disjoint from right` failed\n  left: (-∞, 1.5]\n right: (1, 2]"
+        );
+        assert_eq!(
+            panic_message(|| assert_overlaps!(0..1, 2..3, "ids {} and {}", 7, 9)),
+            "assertion `left overlaps right` failed: ids 7 and 9\n  left: [0, 1)\n right: [2, 3)"
+        );
+    }
+
+    #[test]
+    fn one_sided_failure_messages() {
+        assert_eq!(
+            panic_message(|| assert_overlaps!(Lopsided, 7..)),
+            "assertion `left overlaps right` failed: `left.overlaps(&right)` is true \
+             but `right.overlaps(&left)` is false\n  left: [0, 5)\n right: [7, ∞)"
+        );
+        assert_eq!(
+            panic_message(|| assert_disjoint!(7.., Lopsided)),
+            "assertion `left is disjoint from right` failed: `left.overlaps(&right)` is false \
+             but `right.overlaps(&left)` is true\n  left: [7, ∞)\n right: [0, 5)"
+        );
+    }
+}
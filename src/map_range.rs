@@ -0,0 +1,116 @@
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Maps the endpoints of a std range while keeping its range type.
+///
+/// [`RangeBounds::map`](crate::RangeBounds::map) works on any range but
+/// returns a `(Bound<U>, Bound<U>)` pair. `map_range` is implemented for
+/// each of the std range types instead, so mapping a `Range<T>` gives a
+/// `Range<U>`, a `RangeInclusive<T>` a `RangeInclusive<U>`, and so on. As
+/// with `map`, the result only describes the image of the range if `f`
+/// preserves order.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::MapRange;
+///
+/// let millis: std::ops::Range<u64> = (3..10).map_range(|secs| secs * 1000);
+/// assert_eq!(millis, 3000..10000);
+/// assert_eq!((..=5u32).map_range(u64::from), ..=5u64);
+/// ```
+pub trait MapRange<T> {
+    /// The same range type over `U`.
+    type Output<U>;
+
+    /// Returns the range with `f` applied to each endpoint.
+    ///
+    /// A [`RangeInclusive`] is rebuilt from its `start()` and `end()`, so
+    /// one exhausted by iteration comes back unexhausted.
+    #[must_use]
+    fn map_range<U, F: Fn(T) -> U>(self, f: F) -> Self::Output<U>;
+}
+
+impl<T> MapRange<T> for Range<T> {
+    type Output<U> = Range<U>;
+
+    fn map_range<U, F: Fn(T) -> U>(self, f: F) -> Range<U> {
+        f(self.start)..f(self.end)
+    }
+}
+
+impl<T> MapRange<T> for RangeInclusive<T> {
+    type Output<U> = RangeInclusive<U>;
+
+    fn map_range<U, F: Fn(T) -> U>(self, f: F) -> RangeInclusive<U> {
+        let (start, end) = self.into_inner();
+        f(start)..=f(end)
+    }
+}
+
+impl<T> MapRange<T> for RangeFrom<T> {
+    type Output<U> = RangeFrom<U>;
+
+    fn map_range<U, F: Fn(T) -> U>(self, f: F) -> RangeFrom<U> {
+        f(self.start)..
+    }
+}
+
+impl<T> MapRange<T> for RangeTo<T> {
+    type Output<U> = RangeTo<U>;
+
+    fn map_range<U, F: Fn(T) -> U>(self, f: F) -> RangeTo<U> {
+        ..f(self.end)
+    }
+}
+
+impl<T> MapRange<T> for RangeToInclusive<T> {
+    type Output<U> = RangeToInclusive<U>;
+
+    fn map_range<U, F: Fn(T) -> U>(self, f: F) -> RangeToInclusive<U> {
+        ..=f(self.end)
+    }
+}
+
+impl<T> MapRange<T> for RangeFull {
+    type Output<U> = RangeFull;
+
+    fn map_range<U, F: Fn(T) -> U>(self, _f: F) -> RangeFull {
+        ..
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RangeBounds;
+
+    #[test]
+    fn keeps_the_range_type() {
+        let range: Range<i64> = (3..10).map_range(i64::from);
+        assert_eq!(range, 3..10);
+        let inclusive: RangeInclusive<f64> = (1..=4).map_range(|x: i32| f64::from(x) / 2.0);
+        assert_eq!(inclusive, 0.5..=2.0);
+        let from: RangeFrom<u32> = (7u8..).map_range(u32::from);
+        assert_eq!(from, 7..);
+        let to: RangeTo<i32> = (..5).map_range(|x: i32| -x);
+        assert_eq!(to, ..-5);
+        let to_inclusive: RangeToInclusive<char> = (..=b'c').map_range(char::from);
+        assert_eq!(to_inclusive, ..='c');
+        let full: RangeFull = MapRange::<i32>::map_range(.., |x: i32| x * 2);
+        assert_eq!(full, ..);
+    }
+
+    #[test]
+    fn agrees_with_map() {
+        let double = |x: i32| x * 2;
+        let mapped = (3..=9).map_range(double);
+        assert_eq!(
+            (mapped.start_bound().cloned(), mapped.end_bound().cloned()),
+            RangeBounds::map(&(3..=9), |&x| double(x))
+        );
+        assert_eq!(
+            (Range { start: 4, end: 1 }).map_range(double),
+            Range { start: 8, end: 2 }
+        );
+    }
+}
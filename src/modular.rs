@@ -0,0 +1,164 @@
+use core::cmp::Ordering;
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::RangeBounds;
+
+/// A half-open range on a circular domain, such as angles or times of day,
+/// that wraps around when its start is past its end.
+///
+/// `ModularRange::new(start, end)` holds the values from `start` up to but
+/// excluding `end`. If `start < end` that is the ordinary range
+/// `start..end`. If `start > end` the range runs from `start` to the top of
+/// the domain and continues from the bottom up to `end`, so
+/// `ModularRange::new(350, 10)` holds `350..360` and `0..10` for degrees.
+/// The domain's limits are never named: they are whatever values `T` is
+/// used with, and every value passed in is assumed to lie within them. A
+/// range whose start equals its end, or whose endpoints cannot be
+/// compared, is empty.
+///
+/// A wrapping range is two pieces, so like [`Complement`](crate::Complement)
+/// it does not implement [`RangeBounds`] and provides its own predicates.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::ModularRange;
+///
+/// let north = ModularRange::new(350, 10);
+/// assert!( north.contains(&355));
+/// assert!( north.contains(&5));
+/// assert!(!north.contains(&180));
+/// assert!( north.overlaps(&ModularRange::new(0, 90)));
+/// assert!(!north.overlaps(&ModularRange::new(90, 270)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModularRange<T> {
+    /// The first value in the range.
+    pub start: T,
+    /// The value just past the end of the range.
+    pub end: T,
+}
+
+impl<T> ModularRange<T> {
+    /// Returns the range from `start` up to but excluding `end`, wrapping
+    /// around if `start > end`.
+    pub const fn new(start: T, end: T) -> Self {
+        ModularRange { start, end }
+    }
+}
+
+impl<T: PartialOrd> ModularRange<T> {
+    /// Returns `true` if the range wraps around the top of the domain,
+    /// because its start is past its end.
+    #[must_use]
+    pub fn wraps(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Returns `true` if the range holds no values, because its start equals
+    /// its end or the two cannot be compared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !matches!(
+            self.start.partial_cmp(&self.end),
+            Some(Ordering::Less | Ordering::Greater)
+        )
+    }
+
+    /// Returns `true` if `item` is in the range.
+    #[must_use]
+    pub fn contains(&self, item: &T) -> bool {
+        self.pieces()
+            .into_iter()
+            .flatten()
+            .any(|piece| RangeBounds::<T>::contains(&piece, item))
+    }
+
+    /// Returns `true` if the two ranges share a value.
+    #[must_use]
+    pub fn overlaps(&self, other: &ModularRange<T>) -> bool {
+        self.pieces().into_iter().flatten().any(|piece| {
+            other
+                .pieces()
+                .into_iter()
+                .flatten()
+                .any(|other| RangeBounds::<T>::overlaps(&piece, &other))
+        })
+    }
+
+    /// Splits the range into the ordinary ranges it covers: one for a range
+    /// that does not wrap, one running up from `start` and one running up to
+    /// `end` for a range that does, and none for an empty range.
+    #[allow(clippy::type_complexity)]
+    fn pieces(&self) -> [Option<(Bound<&T>, Bound<&T>)>; 2] {
+        match self.start.partial_cmp(&self.end) {
+            Some(Ordering::Less) => [Some((Included(&self.start), Excluded(&self.end))), None],
+            Some(Ordering::Greater) => [
+                Some((Included(&self.start), Unbounded)),
+                Some((Unbounded, Excluded(&self.end))),
+            ],
+            _ => [None, None],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains() {
+        let north = ModularRange::new(350, 10);
+        assert!(north.wraps());
+        assert!(north.contains(&355));
+        assert!(north.contains(&350));
+        assert!(north.contains(&0));
+        assert!(north.contains(&5));
+        assert!(!north.contains(&10));
+        assert!(!north.contains(&180));
+        assert!(!north.contains(&349));
+
+        let east = ModularRange::new(45, 135);
+        assert!(!east.wraps());
+        assert!(east.contains(&45));
+        assert!(east.contains(&90));
+        assert!(!east.contains(&135));
+        assert!(!east.contains(&355));
+
+        let empty = ModularRange::new(10, 10);
+        assert!(empty.is_empty());
+        assert!(!empty.contains(&10));
+        assert!(!empty.contains(&0));
+        assert!(ModularRange::new(f64::NAN, 1.0).is_empty());
+    }
+
+    #[test]
+    fn overlaps() {
+        let north = ModularRange::new(350, 10);
+        let night = ModularRange::new(22, 6);
+        assert!(north.overlaps(&ModularRange::new(355, 5)));
+        assert!(north.overlaps(&ModularRange::new(0, 90)));
+        assert!(north.overlaps(&ModularRange::new(270, 355)));
+        assert!(!north.overlaps(&ModularRange::new(10, 350)));
+        assert!(!north.overlaps(&ModularRange::new(90, 270)));
+        // Two wrapping ranges always share the values around the top.
+        assert!(north.overlaps(&night));
+        assert!(!north.overlaps(&ModularRange::new(5, 5)));
+
+        let ranges = [
+            north,
+            night,
+            ModularRange::new(0, 360),
+            ModularRange::new(359, 1),
+            ModularRange::new(100, 200),
+            ModularRange::new(200, 100),
+            ModularRange::new(7, 7),
+        ];
+        for a in ranges {
+            for b in ranges {
+                let expected = (0..360).any(|x| a.contains(&x) && b.contains(&x));
+                assert_eq!(a.overlaps(&b), expected, "{a:?} {b:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Ranges of IP addresses.
+//!
+//! [`Ipv4Addr`] and [`Ipv6Addr`] are [`Ord`], so ranges of addresses
+//! already get every [`RangeBounds`] method. The helpers here build the
+//! range covered by a CIDR block, which turns subnet questions into range
+//! questions:
+//!
+//! ```
+//! use rangebounds_overlaps::net::from_cidr;
+//! use rangebounds_overlaps::RangeBounds;
+//! use std::net::Ipv4Addr;
+//!
+//! let office = from_cidr(Ipv4Addr::new(10, 1, 0, 0), 16);
+//! let lab = from_cidr(Ipv4Addr::new(10, 1, 42, 0), 24);
+//! let guests = from_cidr(Ipv4Addr::new(10, 2, 0, 0), 16);
+//! assert!(office.overlaps(&lab));
+//! assert!(office.contains_range(&lab));
+//! assert!(!office.overlaps(&guests));
+//! ```
+//!
+//! [`RangeBounds`]: crate::RangeBounds
+
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::ops::RangeInclusive;
+
+/// Returns the addresses in the IPv4 block `prefix/bits`, from its network
+/// address to its broadcast address.
+///
+/// Host bits set in `prefix` are ignored, so `10.1.2.3/16` gives the same
+/// range as `10.1.0.0/16`.
+///
+/// # Panics
+///
+/// Panics if `bits` is greater than 32.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::net::from_cidr;
+/// use std::net::Ipv4Addr;
+///
+/// assert_eq!(
+///     from_cidr(Ipv4Addr::new(192, 168, 7, 9), 20),
+///     Ipv4Addr::new(192, 168, 0, 0)..=Ipv4Addr::new(192, 168, 15, 255),
+/// );
+/// ```
+pub fn from_cidr(prefix: Ipv4Addr, bits: u8) -> RangeInclusive<Ipv4Addr> {
+    assert!(bits <= 32, "IPv4 prefix length must be at most 32");
+    let mask = u32::MAX.checked_shl(32 - u32::from(bits)).unwrap_or(0);
+    let network = u32::from(prefix) & mask;
+    Ipv4Addr::from(network)..=Ipv4Addr::from(network | !mask)
+}
+
+/// Returns the addresses in the IPv6 block `prefix/bits`, like
+/// [`from_cidr`] does for IPv4.
+///
+/// # Panics
+///
+/// Panics if `bits` is greater than 128.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::net::from_cidr_v6;
+/// use std::net::Ipv6Addr;
+///
+/// let block = from_cidr_v6("2001:db8::".parse().unwrap(), 32);
+/// assert!(block.contains(&"2001:db8:ffff::1".parse::<Ipv6Addr>().unwrap()));
+/// assert!(!block.contains(&"2001:db9::".parse::<Ipv6Addr>().unwrap()));
+/// ```
+pub fn from_cidr_v6(prefix: Ipv6Addr, bits: u8) -> RangeInclusive<Ipv6Addr> {
+    assert!(bits <= 128, "IPv6 prefix length must be at most 128");
+    let mask = u128::MAX.checked_shl(128 - u32::from(bits)).unwrap_or(0);
+    let network = u128::from(prefix) & mask;
+    Ipv6Addr::from(network)..=Ipv6Addr::from(network | !mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RangeBounds;
+    use core::ops::Bound::Included;
+
+    fn v4(a: u8, b: u8, c: u8, d: u8) -> Ipv4Addr {
+        Ipv4Addr::new(a, b, c, d)
+    }
+
+    #[test]
+    fn block_limits() {
+        assert_eq!(
+            from_cidr(v4(10, 1, 2, 3), 16),
+            v4(10, 1, 0, 0)..=v4(10, 1, 255, 255)
+        );
+        assert_eq!(
+            from_cidr(v4(10, 1, 2, 3), 32),
+            v4(10, 1, 2, 3)..=v4(10, 1, 2, 3)
+        );
+        assert_eq!(
+            from_cidr(v4(10, 1, 2, 3), 0),
+            Ipv4Addr::UNSPECIFIED..=Ipv4Addr::BROADCAST
+        );
+        assert_eq!(
+            from_cidr(v4(172, 31, 0, 0), 12),
+            v4(172, 16, 0, 0)..=v4(172, 31, 255, 255)
+        );
+        assert_eq!(
+            from_cidr_v6(Ipv6Addr::LOCALHOST, 0),
+            Ipv6Addr::UNSPECIFIED..=Ipv6Addr::from(u128::MAX)
+        );
+        assert_eq!(
+            from_cidr_v6(Ipv6Addr::LOCALHOST, 128),
+            Ipv6Addr::LOCALHOST..=Ipv6Addr::LOCALHOST
+        );
+    }
+
+    #[test]
+    fn nested_subnets() {
+        let outer = from_cidr(v4(192, 168, 0, 0), 16);
+        let inner = from_cidr(v4(192, 168, 10, 0), 24);
+        assert!(outer.overlaps(&inner));
+        assert!(inner.overlaps(&outer));
+        assert!(outer.contains_range(&inner));
+        assert!(!inner.contains_range(&outer));
+        assert_eq!(
+            outer.intersection(&inner),
+            Some((
+                Included(v4(192, 168, 10, 0)),
+                Included(v4(192, 168, 10, 255))
+            ))
+        );
+        assert!(inner.contains(&v4(192, 168, 10, 255)));
+        assert!(!inner.contains(&v4(192, 168, 11, 0)));
+    }
+
+    #[test]
+    fn sibling_subnets_are_disjoint() {
+        let low = from_cidr(v4(10, 0, 0, 0), 25);
+        let high = from_cidr(v4(10, 0, 0, 128), 25);
+        assert!(!low.overlaps(&high));
+        assert!(!high.overlaps(&low));
+        assert!(low.is_before(&high));
+        assert!(from_cidr(v4(10, 0, 0, 0), 24).contains_range(&high));
+
+        let doc = from_cidr_v6("2001:db8::".parse().unwrap(), 32);
+        let other = from_cidr_v6("2001:db9::".parse().unwrap(), 32);
+        assert!(!doc.overlaps(&other));
+        let start: Ipv6Addr = "2001:db8:1::".parse().unwrap();
+        assert!(doc.overlaps(&(start..)));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 32")]
+    fn rejects_long_prefixes() {
+        let _ = from_cidr(Ipv4Addr::LOCALHOST, 33);
+    }
+}
@@ -0,0 +1,136 @@
+use core::ops::Sub;
+use core::time::Duration;
+
+use crate::sealed::Sealed;
+
+/// Addition that reports overflow instead of panicking or wrapping.
+///
+/// Used by [`RangeBounds::checked_offset`](crate::RangeBounds::checked_offset).
+/// Implemented for the primitive integer types and [`Duration`], by
+/// forwarding to their inherent `checked_add` methods.
+pub trait CheckedAdd: Sized {
+    /// Returns `self + rhs`, or `None` if the result does not fit in `Self`.
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add {
+    ($($t:ty)*) => {
+        $(
+            impl CheckedAdd for $t {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_add! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize Duration }
+
+/// Addition that stops at the type's greatest value instead of overflowing.
+///
+/// Used by
+/// [`RangeBounds::saturating_offset`](crate::RangeBounds::saturating_offset).
+/// Implemented for the primitive integer types and [`Duration`], by
+/// forwarding to their inherent `saturating_add` methods.
+pub trait SaturatingAdd: Sized {
+    /// Returns `self + rhs`, or the greatest value of `Self` if the result
+    /// would be greater than that.
+    fn saturating_add(&self, rhs: &Self) -> Self;
+}
+
+/// Subtraction that stops at the type's least value instead of
+/// overflowing, which for [`Duration`] and the unsigned integers is zero.
+///
+/// Used by
+/// [`RangeBounds::saturating_offset_sub`](crate::RangeBounds::saturating_offset_sub).
+/// Implemented for the primitive integer types and [`Duration`], by
+/// forwarding to their inherent `saturating_sub` methods.
+pub trait SaturatingSub: Sized {
+    /// Returns `self - rhs`, or the least value of `Self` if the result
+    /// would be less than that.
+    fn saturating_sub(&self, rhs: &Self) -> Self;
+}
+
+macro_rules! impl_saturating {
+    ($($t:ty)*) => {
+        $(
+            impl SaturatingAdd for $t {
+                fn saturating_add(&self, rhs: &Self) -> Self {
+                    <$t>::saturating_add(*self, *rhs)
+                }
+            }
+
+            impl SaturatingSub for $t {
+                fn saturating_sub(&self, rhs: &Self) -> Self {
+                    <$t>::saturating_sub(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize Duration }
+
+/// A floating-point type, for the methods that take a tolerance.
+///
+/// Used by [`RangeBounds::overlaps_within`](crate::RangeBounds::overlaps_within)
+/// and [`RangeBounds::contains_approx`](crate::RangeBounds::contains_approx),
+/// which measure distances by subtraction. Floating-point subtraction goes
+/// to infinity rather than overflowing, so this is implemented for [`f32`]
+/// and [`f64`] only, and sealed.
+pub trait Float: PartialOrd + Sub<Output = Self> + Copy + Sealed {
+    /// Zero.
+    const ZERO: Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ty)*) => {
+        $(
+            impl Sealed for $t {}
+
+            impl Float for $t {
+                const ZERO: Self = 0.0;
+            }
+        )*
+    };
+}
+
+impl_float! { f32 f64 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_add() {
+        assert_eq!(CheckedAdd::checked_add(&3u8, &4), Some(7));
+        assert_eq!(CheckedAdd::checked_add(&u8::MAX, &1), None);
+        assert_eq!(CheckedAdd::checked_add(&-3i32, &-4), Some(-7));
+        assert_eq!(CheckedAdd::checked_add(&i64::MIN, &-1), None);
+        assert_eq!(
+            CheckedAdd::checked_add(&Duration::from_secs(1), &Duration::from_millis(500)),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            CheckedAdd::checked_add(&Duration::MAX, &Duration::from_nanos(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(SaturatingAdd::saturating_add(&250u8, &10), u8::MAX);
+        assert_eq!(SaturatingSub::saturating_sub(&3u8, &10), 0);
+        assert_eq!(SaturatingSub::saturating_sub(&-100i8, &100), i8::MIN);
+        assert_eq!(SaturatingAdd::saturating_add(&-3i32, &-4), -7);
+        assert_eq!(
+            SaturatingSub::saturating_sub(&Duration::from_secs(1), &Duration::from_secs(10)),
+            Duration::ZERO
+        );
+        assert_eq!(
+            SaturatingAdd::saturating_add(&Duration::MAX, &Duration::from_nanos(1)),
+            Duration::MAX
+        );
+    }
+}
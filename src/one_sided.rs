@@ -0,0 +1,96 @@
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use crate::RangeBounds;
+
+/// A range bounded only from below by a lone [`Bound`].
+///
+/// `Lower(Included(5))` is the range `5..`, and `Lower(Unbounded)` is `..`.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{Lower, RangeBounds};
+/// use std::ops::Bound::*;
+///
+/// assert!( Lower(Included(0)).overlaps(&(3..5)));
+/// assert!(!Lower(Excluded(5)).overlaps(&(3..=5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lower<T>(pub Bound<T>);
+
+/// A range bounded only from above by a lone [`Bound`].
+///
+/// `Upper(Excluded(5))` is the range `..5`, and `Upper(Unbounded)` is `..`.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::{RangeBounds, Upper};
+/// use std::ops::Bound::*;
+///
+/// assert!( Upper(Included(3)).overlaps(&(3..5)));
+/// assert!(!Upper(Excluded(3)).overlaps(&(3..5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Upper<T>(pub Bound<T>);
+
+impl<T> RangeBounds<T> for Lower<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        match self.0 {
+            Included(ref start) => Included(start),
+            Excluded(ref start) => Excluded(start),
+            Unbounded => Unbounded,
+        }
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+}
+
+impl<T> RangeBounds<T> for Upper<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Unbounded
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        match self.0 {
+            Included(ref end) => Included(end),
+            Excluded(ref end) => Excluded(end),
+            Unbounded => Unbounded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_behaves_like_range_from() {
+        assert_eq!(Lower(Included(0)).start_bound(), Included(&0));
+        assert_eq!(Lower(Included(0)).end_bound(), Unbounded);
+        for other in [3..5, -5..-1, -1..0, -1..1] {
+            assert_eq!(Lower(Included(0)).overlaps(&other), (0..).overlaps(&other));
+        }
+        assert!(!Lower(Excluded(0)).overlaps(&(-1..=0)));
+        assert!(Lower(Excluded(0)).overlaps(&(-1..=1)));
+        assert!(Lower::<i32>(Unbounded).overlaps(&(-100..-99)));
+    }
+
+    #[test]
+    fn upper_behaves_like_range_to() {
+        assert_eq!(Upper(Excluded(5)).start_bound(), Unbounded);
+        assert_eq!(Upper(Excluded(5)).end_bound(), Excluded(&5));
+        for other in [3..5, 5..8, 4..5, 6..7] {
+            assert_eq!(Upper(Excluded(5)).overlaps(&other), (..5).overlaps(&other));
+            assert_eq!(Upper(Included(5)).overlaps(&other), (..=5).overlaps(&other));
+        }
+        assert!(Upper::<i32>(Unbounded).overlaps(&(100..101)));
+    }
+
+    #[test]
+    fn lower_and_upper() {
+        assert!(Lower(Included(5)).overlaps(&Upper(Included(5))));
+        assert!(!Lower(Included(5)).overlaps(&Upper(Excluded(5))));
+        assert!(Lower(Included(5)).touches(&Upper(Excluded(5))));
+    }
+}
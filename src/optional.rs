@@ -0,0 +1,107 @@
+use crate::RangeBounds;
+
+/// A range filter that may be absent, where `None` matches nothing.
+///
+/// [`RangeBounds`] describes a range through references to its endpoint
+/// values, so it cannot express a range that contains nothing without
+/// having some value to point at. `OptionalRange` instead provides its own
+/// versions of the common predicates: `Some(range)` delegates to the range
+/// and `None` behaves like an [empty](RangeBounds::is_empty) range.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::OptionalRange;
+///
+/// let filter = OptionalRange(Some(3..5));
+/// assert!(filter.overlaps(&(0..4)));
+///
+/// let filter = OptionalRange(None::<std::ops::Range<i32>>);
+/// assert!(!filter.overlaps(&(0..10)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OptionalRange<R>(pub Option<R>);
+
+impl<R> OptionalRange<R> {
+    /// Returns `true` if `item` is contained in the range, and `false` for
+    /// `None`.
+    ///
+    /// See [`RangeBounds::contains`].
+    #[must_use]
+    pub fn contains<T, U>(&self, item: &U) -> bool
+    where
+        R: RangeBounds<T>,
+        T: ?Sized + PartialOrd<U>,
+        U: ?Sized + PartialOrd<T>,
+    {
+        self.0.as_ref().is_some_and(|range| range.contains(item))
+    }
+
+    /// Returns `true` if the range contains no elements, which is always
+    /// the case for `None`.
+    ///
+    /// See [`RangeBounds::is_empty`].
+    #[must_use]
+    pub fn is_empty<T>(&self) -> bool
+    where
+        R: RangeBounds<T>,
+        T: PartialOrd,
+    {
+        self.0.as_ref().is_none_or(|range| range.is_empty())
+    }
+
+    /// Returns `true` if there exists an element present in both ranges,
+    /// and `false` for `None`.
+    ///
+    /// See [`RangeBounds::overlaps`].
+    #[must_use]
+    pub fn overlaps<T, O, E>(&self, other: &O) -> bool
+    where
+        R: RangeBounds<T>,
+        T: PartialOrd + PartialOrd<E>,
+        E: ?Sized + PartialOrd + PartialOrd<T>,
+        O: RangeBounds<E>,
+    {
+        self.0.as_ref().is_some_and(|range| range.overlaps(other))
+    }
+}
+
+impl<R> From<Option<R>> for OptionalRange<R> {
+    fn from(range: Option<R>) -> Self {
+        OptionalRange(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::{Bound, Range};
+
+    #[test]
+    fn none_matches_nothing() {
+        let none = OptionalRange(None::<Range<i32>>);
+        assert!(!none.overlaps(&(0..10)));
+        assert!(!none.overlaps(&(..)));
+        assert!(!none.contains(&0));
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn some_delegates() {
+        let some = OptionalRange(Some((Bound::Excluded(3), Bound::Included(5))));
+        assert!(some.overlaps(&(5..10)));
+        assert!(!some.overlaps(&(0..=3)));
+        assert!(some.contains(&5));
+        assert!(!some.contains(&3));
+        assert!(!some.is_empty());
+        assert!(OptionalRange(Some(5..5)).is_empty());
+        assert!(!OptionalRange(Some(5..5)).overlaps(&(0..10)));
+    }
+
+    #[test]
+    fn from_option() {
+        let filter: OptionalRange<_> = Some(0..3).into();
+        assert_eq!(filter, OptionalRange(Some(0..3)));
+        assert_eq!(OptionalRange::<Range<i32>>::default(), OptionalRange(None));
+    }
+}
@@ -0,0 +1,230 @@
+use alloc::vec::Vec;
+use core::ops::Bound;
+
+use crate::RangeBounds;
+
+/// A set of values stored as sorted, disjoint ranges.
+///
+/// Inserted ranges that overlap or [touch](RangeBounds::touches) ranges
+/// already in the set are merged with them, so the stored ranges always
+/// have a gap between each other, as from
+/// [`merge_overlapping`](crate::merge_overlapping). Membership and overlap
+/// queries binary search the sorted ranges.
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::RangeSet;
+///
+/// let mut set = RangeSet::new();
+/// set.insert(0..3);
+/// set.insert(10..20);
+/// set.insert(3..5);
+/// assert_eq!(set.len(), 2);
+/// assert!( set.contains(&4));
+/// assert!(!set.contains(&5));
+/// assert!( set.overlaps(&(15..30)));
+/// assert!(!set.overlaps(&(5..10)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<(Bound<T>, Bound<T>)>,
+}
+
+impl<T> RangeSet<T> {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// Returns the number of disjoint ranges in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if the set contains no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns an iterator over the set's disjoint ranges, sorted by start.
+    pub fn iter(&self) -> core::slice::Iter<'_, (Bound<T>, Bound<T>)> {
+        self.ranges.iter()
+    }
+}
+
+impl<T: Ord> RangeSet<T> {
+    /// Adds the values in `range` to the set, merging it with every stored
+    /// range it overlaps or touches.
+    ///
+    /// An [empty](RangeBounds::is_empty) range adds nothing.
+    pub fn insert<R: RangeBounds<T>>(&mut self, range: R)
+    where
+        T: Clone,
+    {
+        if range.is_empty() {
+            return;
+        }
+        // Stored ranges before `range` with a gap between them come first,
+        // then the run of ranges it connects with, then the rest.
+        let lo = self.ranges.partition_point(|stored| {
+            stored.union(&range).is_none()
+                && crate::cmp_start_bound(stored.start_bound(), range.start_bound()).is_lt()
+        });
+        let hi = lo
+            + self.ranges[lo..]
+                .iter()
+                .take_while(|stored| stored.union(&range).is_some())
+                .count();
+        let merged = self.ranges[lo..hi].iter().fold(
+            (range.start_bound().cloned(), range.end_bound().cloned()),
+            |merged, stored| merged.union(stored).expect("connected ranges merge"),
+        );
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// Returns `true` if `value` is in one of the set's ranges.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        crate::find_containing(&self.ranges, value).is_some()
+    }
+
+    /// Returns `true` if `query` shares an element with one of the set's
+    /// ranges.
+    #[must_use]
+    pub fn overlaps<R: RangeBounds<T>>(&self, query: &R) -> bool {
+        let index = self
+            .ranges
+            .partition_point(|stored| stored.is_before(query));
+        self.ranges
+            .get(index)
+            .is_some_and(|stored| stored.overlaps(query))
+    }
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a (Bound<T>, Bound<T>);
+    type IntoIter = core::slice::Iter<'a, (Bound<T>, Bound<T>)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use std::vec;
+
+    fn ranges<T: Clone>(set: &RangeSet<T>) -> Vec<(Bound<T>, Bound<T>)> {
+        set.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_disjoint() {
+        let mut set = RangeSet::new();
+        set.insert(10..20);
+        set.insert(0..3);
+        set.insert((Excluded(30), Unbounded));
+        set.insert(5..5);
+        assert_eq!(
+            ranges(&set),
+            vec![
+                (Included(0), Excluded(3)),
+                (Included(10), Excluded(20)),
+                (Excluded(30), Unbounded),
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_with_merge() {
+        let mut set = RangeSet::new();
+        set.insert(0..3);
+        set.insert(5..8);
+        set.insert(10..12);
+        set.insert(20..25);
+
+        set.insert(2..=10);
+        assert_eq!(
+            ranges(&set),
+            vec![(Included(0), Excluded(12)), (Included(20), Excluded(25))]
+        );
+
+        set.insert(12..20);
+        assert_eq!(ranges(&set), vec![(Included(0), Excluded(25))]);
+
+        set.insert((Excluded(25), Excluded(30)));
+        assert_eq!(set.len(), 2);
+        set.insert(..=25);
+        assert_eq!(ranges(&set), vec![(Unbounded, Excluded(30))]);
+    }
+
+    #[test]
+    fn membership() {
+        let mut set = RangeSet::new();
+        set.insert(0..3);
+        set.insert(10..=20);
+        set.insert((Excluded(30), Unbounded));
+
+        for value in -1..40 {
+            let expected = (0..3).contains(&value) || (10..=20).contains(&value) || value > 30;
+            assert_eq!(set.contains(&value), expected, "{value}");
+        }
+
+        assert!(set.overlaps(&(2..5)));
+        assert!(set.overlaps(&(20..)));
+        assert!(!set.overlaps(&(..0)));
+        assert!(!set.overlaps(&(3..10)));
+        assert!(!set.overlaps(&(Excluded(20), Included(30))));
+        assert!(set.overlaps(&(Excluded(20), Included(31))));
+        assert!(!set.overlaps(&(15..15)));
+        assert!(!RangeSet::<i32>::new().overlaps(&(..)));
+        assert!(RangeSet::<i32>::default().is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let bounds = |value| [Included(value), Excluded(value), Unbounded];
+        let mut all = Vec::new();
+        for start in 0..6 {
+            for end in start..6 {
+                for start in bounds(start) {
+                    for end in bounds(end) {
+                        all.push((start, end));
+                    }
+                }
+            }
+        }
+        // Insert a deterministic but scattered selection of ranges.
+        for step in [7, 11, 13] {
+            let mut set = RangeSet::new();
+            let mut inserted = Vec::new();
+            for range in all.iter().step_by(step).take(6) {
+                set.insert(*range);
+                inserted.push(*range);
+                // Half steps fall between the integer endpoints.
+                let set = RangeSet {
+                    ranges: set.iter().map(|r| r.map(|&x| x * 2)).collect(),
+                };
+                for value in -2..14 {
+                    let expected = inserted.iter().any(|r| r.map(|&x| x * 2).contains(&value));
+                    assert_eq!(set.contains(&value), expected, "{inserted:?} at {value}");
+                }
+            }
+            assert!(set
+                .iter()
+                .zip(set.iter().skip(1))
+                .all(|(a, b)| a.is_before(b) && !a.touches(b)));
+        }
+    }
+}
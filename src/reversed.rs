@@ -0,0 +1,104 @@
+use core::iter::Rev;
+use core::ops::Bound;
+
+use crate::{Countable, Iter, RangeBounds};
+
+/// A range marked for descending traversal.
+///
+/// Ranges carry no direction, so `Reversed` keeps the inner range's bounds
+/// exactly as they are: it holds the same values, and every
+/// [`RangeBounds`] predicate, such as [`contains`](RangeBounds::contains)
+/// and [`overlaps`](RangeBounds::overlaps), gives the same answer as on the
+/// inner range. Only iteration changes: [`Reversed::iter`] yields the
+/// values from the greatest down to the least.
+///
+/// This `struct` is created by [`RangeBounds::reversed`].
+///
+/// # Examples
+///
+/// ```
+/// use rangebounds_overlaps::RangeBounds;
+///
+/// let countdown = (3..=6).reversed();
+/// assert_eq!(countdown.iter().collect::<Vec<_>>(), [6, 5, 4, 3]);
+/// assert!(countdown.contains(&3));
+/// assert!(countdown.overlaps(&(0..4)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reversed<R>(pub R);
+
+impl<R> Reversed<R> {
+    /// Returns an iterator over the values in a range of integers, in
+    /// descending order.
+    ///
+    /// This is [`RangeBounds::iter`] reversed. Calling that method through
+    /// the trait, as `RangeBounds::iter(&reversed)`, still iterates in
+    /// ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either side of the range is `Unbounded`.
+    pub fn iter<T: Countable>(&self) -> Rev<Iter<T>>
+    where
+        R: RangeBounds<T>,
+    {
+        self.0.iter().rev()
+    }
+}
+
+impl<T: ?Sized, R: RangeBounds<T>> RangeBounds<T> for Reversed<R> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.0.start_bound()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.0.end_bound()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use std::vec::Vec;
+
+    #[test]
+    fn iterates_in_descending_order() {
+        let collect = |range: (Bound<i32>, Bound<i32>)| range.reversed().iter().collect::<Vec<_>>();
+
+        assert_eq!(collect((Included(3), Excluded(10))), [9, 8, 7, 6, 5, 4, 3]);
+        assert_eq!(collect((Excluded(0), Included(3))), [3, 2, 1]);
+        assert_eq!(collect((Included(3), Included(3))), [3]);
+        assert_eq!(collect((Included(3), Excluded(3))), Vec::<i32>::new());
+        assert_eq!(
+            (250u8..=u8::MAX).reversed().iter().collect::<Vec<_>>(),
+            [255, 254, 253, 252, 251, 250]
+        );
+        assert_eq!(
+            (0..5).reversed().iter().rev().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn keeps_membership() {
+        let range = (Excluded(3), Included(10));
+        let reversed = range.reversed();
+        assert_eq!(reversed.start_bound(), Excluded(&3));
+        assert_eq!(reversed.end_bound(), Included(&10));
+        for value in 0..=12 {
+            assert_eq!(reversed.contains(&value), range.contains(&value));
+        }
+        for other in [0..3, 0..4, 10..12, 11..12, 5..5] {
+            assert_eq!(reversed.overlaps(&other), range.overlaps(&other));
+        }
+        assert_eq!(reversed.cardinality(), Some(7));
+        assert!((Unbounded, Excluded(0)).reversed().overlaps(&(..0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "unbounded")]
+    fn unbounded_iteration() {
+        let _ = (..5).reversed().iter();
+    }
+}